@@ -0,0 +1,25 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ecc_rust::{create_secp256k1_weierstrass, EllipticCurve};
+use num_bigint::BigUint;
+
+fn bench_base_point_table(c: &mut Criterion) {
+    let curve = create_secp256k1_weierstrass();
+    let table_curve = create_secp256k1_weierstrass().with_base_point_table();
+    let scalar = BigUint::parse_bytes(
+        b"C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721",
+        16,
+    )
+    .unwrap();
+
+    let mut group = c.benchmark_group("secp256k1 base point");
+    group.bench_function("mul", |b| {
+        b.iter(|| curve.mul(curve.base_point(), black_box(&scalar)))
+    });
+    group.bench_function("mul_base with table", |b| {
+        b.iter(|| table_curve.mul_base(black_box(&scalar)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_base_point_table);
+criterion_main!(benches);
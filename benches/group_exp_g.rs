@@ -0,0 +1,39 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ecc_rust::{FiniteField, Group};
+use num_bigint::BigUint;
+
+// RFC 5114 section 2.1: 1024-bit MODP group with a 160-bit prime order subgroup
+fn rfc5114_1024bit() -> Group {
+    let p = BigUint::parse_bytes(
+        b"B10B8F96A080E01DDE92DE5EAE5D54EC52C99FBCFB06A3C69A6A9DCA52D23B616073E28675A23D189838EF1E2EE652C013ECB4AEA906112324975C3CD49B83BFACCBDD7D90C4BD7098488E9C219A73724EFFD6FAE5644738FAA31A4FF55BCCC0A151AF5F0DC8B4BD45BF37DF365C1A65E68CFDA76D4DA708DF1FB2BC2E4A4371",
+        16,
+    )
+    .unwrap();
+    let q = BigUint::parse_bytes(b"F518AA8781A8DF278ABA4E7D64B7CB9D49462353", 16).unwrap();
+    let g = BigUint::parse_bytes(
+        b"A4D1CBD5C3FD34126765A442EFB99905F8104DD258AC507FD6406CFF14266D31266FEA1E5C41564B777E690F5504F213160217B4B01B886A5E91547F9E2749F4D7FBD7D3B9A92EE1909D0D2263F80A76A6A24C087A091F531DBF0A0169B6A28AD662A4D18E73AFA32D779D5918D08BC8858F4DCEF97C2A24855E6EEB22B3B2E5",
+        16,
+    )
+    .unwrap();
+    let h = FiniteField::exp(&g, &BigUint::from(2u32), &p);
+
+    Group::new(p, q, g, h)
+}
+
+fn bench_group_exp_g(c: &mut Criterion) {
+    let group = rfc5114_1024bit();
+    let table_group = rfc5114_1024bit().precompute_g();
+    let exponent = &group.p - BigUint::from(12345u32);
+
+    let mut bench = c.benchmark_group("RFC 5114 1024-bit g^e");
+    bench.bench_function("exp", |b| {
+        b.iter(|| FiniteField::exp(&group.g, black_box(&exponent), &group.p))
+    });
+    bench.bench_function("exp_g with table", |b| {
+        b.iter(|| table_group.exp_g(black_box(&exponent)))
+    });
+    bench.finish();
+}
+
+criterion_group!(benches, bench_group_exp_g);
+criterion_main!(benches);
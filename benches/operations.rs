@@ -0,0 +1,99 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ecc_rust::{create_secp256k1_weierstrass, EllipticCurve, FiniteField, Secp256k1Fast, ECDSA};
+use num_bigint::BigUint;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+fn scalar() -> BigUint {
+    BigUint::parse_bytes(
+        b"C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721",
+        16,
+    )
+    .unwrap()
+}
+
+fn bench_curve(c: &mut Criterion) {
+    let curve = create_secp256k1_weierstrass();
+    let scalar = scalar();
+    let point = curve.mul(curve.base_point(), &BigUint::from(0xC0FFEEu32));
+
+    let mut group = c.benchmark_group("secp256k1 curve");
+    group.bench_function("mul", |b| {
+        b.iter(|| curve.mul(black_box(&point), black_box(&scalar)))
+    });
+    group.bench_function("mul_redc", |b| {
+        b.iter(|| curve.mul_redc(black_box(&point), black_box(&scalar)))
+    });
+    group.bench_function("mul_glv", |b| {
+        b.iter(|| curve.mul_glv(black_box(&point), black_box(&scalar)))
+    });
+    group.bench_function("add", |b| {
+        b.iter(|| curve.add(black_box(&point), black_box(curve.base_point())))
+    });
+    group.bench_function("double", |b| b.iter(|| curve.double(black_box(&point))));
+    group.finish();
+}
+
+fn bench_fast_curve(c: &mut Criterion) {
+    let curve = Secp256k1Fast::new();
+    let scalar = scalar();
+    let point = curve.mul(curve.base_point(), &BigUint::from(0xC0FFEEu32));
+
+    let mut group = c.benchmark_group("secp256k1 fast curve");
+    group.bench_function("mul", |b| {
+        b.iter(|| curve.mul(black_box(&point), black_box(&scalar)))
+    });
+    group.bench_function("add", |b| {
+        b.iter(|| curve.add(black_box(&point), black_box(curve.base_point())))
+    });
+    group.bench_function("double", |b| b.iter(|| curve.double(black_box(&point))));
+    group.finish();
+}
+
+fn bench_field(c: &mut Criterion) {
+    let curve = create_secp256k1_weierstrass();
+    let p = curve.field_modulus();
+    let a = scalar() % p;
+    // a^2 is always a quadratic residue
+    let square = FiniteField::mul(&a, &a, p);
+
+    let mut group = c.benchmark_group("secp256k1 field");
+    group.bench_function("add", |b| {
+        b.iter(|| FiniteField::add(black_box(&a), black_box(&square), p))
+    });
+    group.bench_function("inv_mul", |b| {
+        b.iter(|| FiniteField::inv_mul(black_box(&a), p))
+    });
+    group.bench_function("sqrt", |b| {
+        b.iter(|| FiniteField::sqrt(black_box(&square), p))
+    });
+    group.finish();
+}
+
+fn bench_ecdsa(c: &mut Criterion) {
+    let ecdsa = ECDSA::new(create_secp256k1_weierstrass());
+    let (private_key, public_key) = ecdsa.generate_keypair_with_rng(&mut StdRng::seed_from_u64(1));
+    let message = scalar();
+    let signature = ecdsa
+        .sign_with_rng(&message, &private_key, &mut StdRng::seed_from_u64(2))
+        .unwrap();
+
+    let mut group = c.benchmark_group("secp256k1 ecdsa");
+    group.bench_function("sign", |b| {
+        let mut rng = StdRng::seed_from_u64(3);
+        b.iter(|| ecdsa.sign_with_rng(black_box(&message), &private_key, &mut rng))
+    });
+    group.bench_function("verify", |b| {
+        b.iter(|| ecdsa.verify(black_box(&message), &signature, &public_key))
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_curve,
+    bench_fast_curve,
+    bench_field,
+    bench_ecdsa
+);
+criterion_main!(benches);
@@ -0,0 +1,14 @@
+#![no_main]
+
+use ecc_rust::{create_secp256k1_weierstrass, EllipticCurve};
+use libfuzzer_sys::fuzz_target;
+
+// decoding arbitrary bytes must return an error or a point on the curve,
+// never panic, and a decoded point must encode back to the same bytes
+fuzz_target!(|data: &[u8]| {
+    let curve = create_secp256k1_weierstrass();
+    if let Ok(point) = curve.from_sec1_compressed(data) {
+        assert!(curve.is_on_curve(&point));
+        assert_eq!(curve.to_sec1_compressed(&point), data);
+    }
+});
@@ -0,0 +1,29 @@
+#![no_main]
+
+use arbitrary::Arbitrary;
+use ecc_rust::{create_secp256k1_weierstrass, create_secp256r1_weierstrass, EllipticCurve, Point};
+use libfuzzer_sys::fuzz_target;
+use num_bigint::BigUint;
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    p256: bool,
+    scalar: [u8; 32],
+}
+
+// scalar·G must survive compression and decompression unchanged
+fuzz_target!(|input: Input| {
+    let curve = if input.p256 {
+        create_secp256r1_weierstrass()
+    } else {
+        create_secp256k1_weierstrass()
+    };
+    let point = curve.mul_base(&BigUint::from_bytes_be(&input.scalar));
+    let encoded = curve.to_sec1_compressed(&point);
+
+    if point == Point::Identity {
+        assert_eq!(encoded, [0x00]);
+        return;
+    }
+    assert_eq!(curve.from_sec1_compressed(&encoded), Ok(point));
+});
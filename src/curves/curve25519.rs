@@ -0,0 +1,30 @@
+use crate::ec::MontgomeryCurve;
+use crate::point::Point;
+use num_bigint::BigUint;
+
+/// Returns the Curve25519 parameters (RFC 7748): y^2 = x^3 + 486662x^2 + x
+/// over 2^255 - 19
+pub fn create_curve25519() -> MontgomeryCurve {
+    let p = BigUint::parse_bytes(
+        b"7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFED",
+        16,
+    )
+    .unwrap();
+    let a = BigUint::from(486662u32);
+    let b = BigUint::from(1u32);
+
+    let x = BigUint::from(9u32);
+    let y = BigUint::parse_bytes(
+        b"20AE19A1B8A086B4E01EDD2C7748D14C923D4D7E6D7C61B229E9C5A27ECED3D9",
+        16,
+    )
+    .unwrap();
+    let g = Point::Coordinates(x, y);
+    let n = BigUint::parse_bytes(
+        b"1000000000000000000000000000000014DEF9DEA2F79CD65812631A5CF5D3ED",
+        16,
+    )
+    .unwrap();
+
+    MontgomeryCurve::new(a, b, p, n, g).with_cofactor(BigUint::from(8u32))
+}
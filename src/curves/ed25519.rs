@@ -0,0 +1,38 @@
+use crate::ec::EdwardsCurve;
+use crate::point::Point;
+use num_bigint::BigUint;
+
+/// Returns the Ed25519 parameters (RFC 8032): -x^2 + y^2 = 1 + dx^2y^2
+/// over 2^255 - 19, with d = -121665/121666
+pub fn create_ed25519() -> EdwardsCurve {
+    let p = BigUint::parse_bytes(
+        b"7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFED",
+        16,
+    )
+    .unwrap();
+    let a = &p - BigUint::from(1u32);
+    let d = BigUint::parse_bytes(
+        b"52036CEE2B6FFE738CC740797779E89800700A4D4141D8AB75EB4DCA135978A3",
+        16,
+    )
+    .unwrap();
+
+    let x = BigUint::parse_bytes(
+        b"216936D3CD6E53FEC0A4E231FDD6DC5C692CC7609525A7B2C9562D608F25D51A",
+        16,
+    )
+    .unwrap();
+    let y = BigUint::parse_bytes(
+        b"6666666666666666666666666666666666666666666666666666666666666658",
+        16,
+    )
+    .unwrap();
+    let g = Point::Coordinates(x, y);
+    let n = BigUint::parse_bytes(
+        b"1000000000000000000000000000000014DEF9DEA2F79CD65812631A5CF5D3ED",
+        16,
+    )
+    .unwrap();
+
+    EdwardsCurve::new(a, d, p, n, g).with_cofactor(BigUint::from(8u32))
+}
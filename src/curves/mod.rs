@@ -1 +1,88 @@
-pub mod secp256k1;
\ No newline at end of file
+use crate::ec::WeierstrassCurve;
+
+pub mod curve25519;
+pub mod ed25519;
+pub mod secp256k1;
+pub mod secp256k1_fast;
+pub mod secp256r1;
+
+type CurveConstructor = fn() -> WeierstrassCurve;
+
+/// The Weierstrass curves known by name. A curve listed under several names
+/// has its canonical name first.
+pub(crate) const NAMED_CURVES: [(&str, CurveConstructor); 4] = [
+    ("secp256k1", secp256k1::create_secp256k1_weierstrass),
+    ("secp256r1", secp256r1::create_secp256r1_weierstrass),
+    ("P-256", secp256r1::create_secp256r1_weierstrass),
+    ("prime256v1", secp256r1::create_secp256r1_weierstrass),
+];
+
+/// The Weierstrass curves known by their ASN.1 object identifier, as used in
+/// X.509 and PKCS#8 `namedCurve` parameters
+const CURVE_OIDS: [(&[u64], CurveConstructor); 2] = [
+    (&[1, 3, 132, 0, 10], secp256k1::create_secp256k1_weierstrass),
+    (
+        &[1, 2, 840, 10045, 3, 1, 7],
+        secp256r1::create_secp256r1_weierstrass,
+    ),
+];
+
+/// Returns the named curve, if it is one this crate knows about
+pub fn lookup_by_name(name: &str) -> Option<WeierstrassCurve> {
+    NAMED_CURVES
+        .iter()
+        .find(|(known, _)| *known == name)
+        .map(|(_, create)| create())
+}
+
+/// Same as `lookup_by_name`
+pub fn by_name(name: &str) -> Option<WeierstrassCurve> {
+    lookup_by_name(name)
+}
+
+/// Returns the curve with the object identifier `oid`, given as its arcs
+/// (e.g. `[1, 3, 132, 0, 10]` for secp256k1), if this crate knows about it
+pub fn from_oid(oid: &[u64]) -> Option<WeierstrassCurve> {
+    CURVE_OIDS
+        .iter()
+        .find(|(known, _)| *known == oid)
+        .map(|(_, create)| create())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curves::secp256k1::create_secp256k1_weierstrass;
+    use crate::curves::secp256r1::create_secp256r1_weierstrass;
+
+    #[test]
+    fn test_lookup_by_name() {
+        let secp256k1 = lookup_by_name("secp256k1").unwrap();
+        assert!(secp256k1 == create_secp256k1_weierstrass());
+        assert_eq!(secp256k1.name(), Some("secp256k1"));
+
+        for alias in ["secp256r1", "P-256", "prime256v1"] {
+            let curve = lookup_by_name(alias).unwrap();
+            assert!(curve == create_secp256r1_weierstrass());
+            assert_eq!(curve.name(), Some("secp256r1"));
+        }
+        assert!(secp256k1 != create_secp256r1_weierstrass());
+
+        assert!(lookup_by_name("secp255k1").is_none());
+    }
+
+    #[test]
+    fn test_from_oid() {
+        let secp256k1 = from_oid(&[1, 3, 132, 0, 10]).unwrap();
+        assert!(secp256k1 == create_secp256k1_weierstrass());
+        assert_eq!(secp256k1.name(), Some("secp256k1"));
+
+        let p256 = from_oid(&[1, 2, 840, 10045, 3, 1, 7]).unwrap();
+        assert!(p256 == create_secp256r1_weierstrass());
+
+        // secp384r1 and a truncated prefix are unknown
+        assert!(from_oid(&[1, 3, 132, 0, 34]).is_none());
+        assert!(from_oid(&[1, 3, 132, 0]).is_none());
+        assert!(from_oid(&[]).is_none());
+    }
+}
@@ -1,6 +1,6 @@
 use crate::ec::WeierstrassCurve;
 use crate::point::Point;
-use num_bigint::BigUint;
+use num_bigint::{BigInt, BigUint};
 
 /// Returns the secp256k1 curve parameters
 pub fn create_secp256k1_weierstrass() -> WeierstrassCurve {
@@ -29,13 +29,33 @@ pub fn create_secp256k1_weierstrass() -> WeierstrassCurve {
     )
     .unwrap();
 
+    // the endomorphism (x, y) ↦ (βx, y) = λ·(x, y), with the lattice basis
+    // from "Guide to Elliptic Curve Cryptography", example 3.73
+    let beta = BigUint::parse_bytes(
+        b"7AE96A2B657C07106E64479EAC3434E99CF0497512F58995C1396C28719501EE",
+        16,
+    )
+    .unwrap();
+    let lambda = BigUint::parse_bytes(
+        b"5363AD4CC05C30E0A5261C028812645A122E22EA20816678DF02967C1B23BD72",
+        16,
+    )
+    .unwrap();
+    let hex = |digits: &[u8]| BigInt::parse_bytes(digits, 16).unwrap();
+    let a1 = hex(b"3086D221A7D46BCDE86C90E49284EB15");
+    let b1 = -hex(b"E4437ED6010E88286F547FA90ABFE4C3");
+    let a2 = hex(b"114CA50F7A8E2F3F657C1108D9D44CFD8");
+
     WeierstrassCurve::new(a, b, p, n, g)
+        .with_cofactor(BigUint::from(1u32))
+        .with_endomorphism(beta, lambda, (a1.clone(), b1), (a2, a1))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::ec::EllipticCurve; // Add this line to import the trait
+    use num_bigint::Sign;
 
     #[test]
     fn test_secp256k1_params() {
@@ -43,16 +63,45 @@ mod tests {
 
         // Verify that the base point is on the curve
         assert!(
-            curve.is_on_curve(&curve.base_point()),
+            curve.is_on_curve(curve.base_point()),
             "Base point is not on the curve"
         );
 
         // Verify that n * G = O (point at infinity)
-        let result = curve.mul(&curve.base_point(), &curve.order());
+        let result = curve.mul(curve.base_point(), curve.order());
         assert_eq!(
             result,
             Point::Identity,
             "n * G did not result in the point at infinity"
         );
     }
+
+    #[test]
+    fn test_secp256k1_cofactor() {
+        let curve = create_secp256k1_weierstrass();
+        let point = curve.mul(curve.base_point(), &BigUint::from(12345u32));
+
+        assert_eq!(curve.cofactor(), Some(&BigUint::from(1u32)));
+        assert_eq!(curve.clear_cofactor(&point), Some(point));
+    }
+
+    #[test]
+    fn test_secp256k1_endomorphism() {
+        let curve = create_secp256k1_weierstrass();
+        let endomorphism = curve.endomorphism().unwrap();
+        let g = curve.base_point();
+
+        assert_eq!(
+            endomorphism.apply(g, curve.field_modulus()),
+            curve.mul(g, &endomorphism.lambda)
+        );
+
+        // both basis vectors lie in the lattice a + bλ ≡ 0 (mod n)
+        let n = BigInt::from(curve.order().clone());
+        let lambda = BigInt::from(endomorphism.lambda.clone());
+        for (a, b) in [&endomorphism.b1, &endomorphism.b2] {
+            let residue = (a + b * &lambda) % &n;
+            assert_eq!(residue.sign(), Sign::NoSign);
+        }
+    }
 }
@@ -0,0 +1,293 @@
+use crate::ec::EllipticCurve;
+use crate::point::Point;
+use crate::u256::{Modulus, U256};
+use num_bigint::BigUint;
+
+const P: Modulus = Modulus::SECP256K1_P;
+const N: Modulus = Modulus::SECP256K1_N;
+
+const B: U256 = U256::from_limbs([7, 0, 0, 0]);
+const GX: U256 = U256::from_limbs([
+    0x59F2815B16F81798,
+    0x029BFCDB2DCE28D9,
+    0x55A06295CE870B07,
+    0x79BE667EF9DCBBAC,
+]);
+const GY: U256 = U256::from_limbs([
+    0x9C47D08FFB10D4B8,
+    0xFD17B448A6855419,
+    0x5DA4FBFC0E1108A8,
+    0x483ADA7726A3C465,
+]);
+
+/// secp256k1 with its field and scalar arithmetic done on fixed-size `U256`
+/// limbs instead of `BigUint`.
+///
+/// It computes the same results as `create_secp256k1_weierstrass()` through
+/// the same `EllipticCurve` interface. Points are converted to Jacobian
+/// coordinates for the duration of an operation, so a scalar multiplication
+/// performs a single field inversion.
+#[derive(Clone, Debug)]
+pub struct Secp256k1Fast {
+    p: BigUint,
+    n: BigUint,
+    h: BigUint,
+    g: Point,
+}
+
+/// A point in Jacobian coordinates `(X, Y, Z)` standing for `(X/Z², Y/Z³)`;
+/// `Z = 0` is the point at infinity
+#[derive(Clone, Copy)]
+struct Jacobian {
+    x: U256,
+    y: U256,
+    z: U256,
+}
+
+impl Jacobian {
+    const INFINITY: Jacobian = Jacobian {
+        x: U256::ONE,
+        y: U256::ONE,
+        z: U256::ZERO,
+    };
+
+    fn from_affine(x: U256, y: U256) -> Self {
+        Jacobian { x, y, z: U256::ONE }
+    }
+
+    fn is_infinity(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    // dbl-2009-l, valid for a = 0
+    fn double(&self) -> Self {
+        if self.is_infinity() || self.y.is_zero() {
+            return Self::INFINITY;
+        }
+        let a = P.square(&self.x);
+        let b = P.square(&self.y);
+        let c = P.square(&b);
+        let x_plus_b = P.add(&self.x, &b);
+        let d = P.sub(&P.sub(&P.square(&x_plus_b), &a), &c);
+        let d = P.add(&d, &d);
+        let e = P.add(&P.add(&a, &a), &a);
+        let f = P.square(&e);
+
+        let x3 = P.sub(&f, &P.add(&d, &d));
+        let c8 = P.add(&c, &c);
+        let c8 = P.add(&c8, &c8);
+        let c8 = P.add(&c8, &c8);
+        let y3 = P.sub(&P.mul(&e, &P.sub(&d, &x3)), &c8);
+        let yz = P.mul(&self.y, &self.z);
+        let z3 = P.add(&yz, &yz);
+        Jacobian {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    // add-2007-bl without the Z = 1 shortcuts
+    fn add(&self, other: &Jacobian) -> Self {
+        if self.is_infinity() {
+            return *other;
+        }
+        if other.is_infinity() {
+            return *self;
+        }
+        let z1z1 = P.square(&self.z);
+        let z2z2 = P.square(&other.z);
+        let u1 = P.mul(&self.x, &z2z2);
+        let u2 = P.mul(&other.x, &z1z1);
+        let s1 = P.mul(&P.mul(&self.y, &other.z), &z2z2);
+        let s2 = P.mul(&P.mul(&other.y, &self.z), &z1z1);
+        let h = P.sub(&u2, &u1);
+        let r = P.sub(&s2, &s1);
+
+        if h.is_zero() {
+            return if r.is_zero() {
+                self.double()
+            } else {
+                Self::INFINITY
+            };
+        }
+
+        let hh = P.square(&h);
+        let hhh = P.mul(&h, &hh);
+        let v = P.mul(&u1, &hh);
+        let x3 = P.sub(&P.sub(&P.square(&r), &hhh), &P.add(&v, &v));
+        let y3 = P.sub(&P.mul(&r, &P.sub(&v, &x3)), &P.mul(&s1, &hhh));
+        let z3 = P.mul(&P.mul(&self.z, &other.z), &h);
+        Jacobian {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+}
+
+impl Secp256k1Fast {
+    pub fn new() -> Self {
+        Secp256k1Fast {
+            p: P.value().to_biguint(),
+            n: N.value().to_biguint(),
+            h: BigUint::from(1u32),
+            g: Point::Coordinates(GX.to_biguint(), GY.to_biguint()),
+        }
+    }
+
+    /// Reduces both coordinates into the field and converts them to limbs
+    fn to_affine_limbs(&self, x: &BigUint, y: &BigUint) -> (U256, U256) {
+        let reduce = |v: &BigUint| U256::from_biguint(&(v % &self.p)).unwrap();
+        (reduce(x), reduce(y))
+    }
+
+    fn to_jacobian(&self, p: &Point) -> Jacobian {
+        match p {
+            Point::Identity => Jacobian::INFINITY,
+            Point::Coordinates(x, y) => {
+                let (x, y) = self.to_affine_limbs(x, y);
+                Jacobian::from_affine(x, y)
+            }
+        }
+    }
+
+    fn to_point(&self, p: &Jacobian) -> Point {
+        if p.is_infinity() {
+            return Point::Identity;
+        }
+        let z_inv = P.inv(&p.z);
+        let z_inv2 = P.square(&z_inv);
+        let x = P.mul(&p.x, &z_inv2);
+        let y = P.mul(&p.y, &P.mul(&z_inv2, &z_inv));
+        Point::Coordinates(x.to_biguint(), y.to_biguint())
+    }
+}
+
+impl Default for Secp256k1Fast {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EllipticCurve for Secp256k1Fast {
+    fn add(&self, p1: &Point, p2: &Point) -> Point {
+        self.to_point(&self.to_jacobian(p1).add(&self.to_jacobian(p2)))
+    }
+
+    fn double(&self, p: &Point) -> Point {
+        self.to_point(&self.to_jacobian(p).double())
+    }
+
+    fn mul(&self, p: &Point, scalar: &BigUint) -> Point {
+        let base = self.to_jacobian(p);
+        let mut result = Jacobian::INFINITY;
+        for i in (0..scalar.bits()).rev() {
+            result = result.double();
+            if scalar.bit(i) {
+                result = result.add(&base);
+            }
+        }
+        self.to_point(&result)
+    }
+
+    fn is_on_curve(&self, a: &Point) -> bool {
+        match a {
+            Point::Coordinates(x, y) => {
+                let (x, y) = self.to_affine_limbs(x, y);
+                let rhs = P.add(&P.mul(&P.square(&x), &x), &B);
+                P.square(&y) == rhs
+            }
+            Point::Identity => true,
+        }
+    }
+
+    fn order(&self) -> &BigUint {
+        &self.n
+    }
+
+    fn cofactor(&self) -> Option<&BigUint> {
+        Some(&self.h)
+    }
+
+    fn base_point(&self) -> &Point {
+        &self.g
+    }
+
+    fn field_modulus(&self) -> &BigUint {
+        &self.p
+    }
+
+    fn reduce_scalar(&self, s: &BigUint) -> BigUint {
+        match U256::from_biguint(s) {
+            Some(s) => N.reduce(&s).to_biguint(),
+            None => s % &self.n,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curves::secp256k1::create_secp256k1_weierstrass;
+    use num_bigint::RandBigInt;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_params_match_secp256k1() {
+        let fast = Secp256k1Fast::new();
+        let reference = create_secp256k1_weierstrass();
+
+        assert_eq!(fast.field_modulus(), reference.field_modulus());
+        assert_eq!(fast.order(), reference.order());
+        assert_eq!(fast.cofactor(), reference.cofactor());
+        assert_eq!(fast.base_point(), reference.base_point());
+        assert!(fast.is_on_curve(fast.base_point()));
+        assert_eq!(fast.mul(fast.base_point(), fast.order()), Point::Identity);
+    }
+
+    #[test]
+    fn test_matches_biguint_curve_on_random_operations() {
+        let fast = Secp256k1Fast::new();
+        let reference = create_secp256k1_weierstrass();
+        let mut rng = StdRng::seed_from_u64(42);
+        let g = reference.base_point();
+
+        for _ in 0..8 {
+            let k1 = rng.gen_biguint_below(reference.order());
+            let k2 = rng.gen_biguint(300);
+            let p1 = reference.mul(g, &k1);
+            let p2 = reference.mul_base(&k2);
+
+            assert_eq!(fast.mul(g, &k1), p1);
+            assert_eq!(fast.mul(g, &k2), p2);
+            assert_eq!(fast.add(&p1, &p2), reference.add(&p1, &p2));
+            assert_eq!(fast.double(&p1), reference.double(&p1));
+            assert_eq!(fast.reduce_scalar(&k2), reference.reduce_scalar(&k2));
+            assert!(fast.is_on_curve(&p1));
+        }
+    }
+
+    #[test]
+    fn test_special_cases() {
+        let fast = Secp256k1Fast::new();
+        let reference = create_secp256k1_weierstrass();
+        let g = fast.base_point();
+        let neg_g = reference.negate(g);
+
+        assert_eq!(fast.add(g, &neg_g), Point::Identity);
+        assert_eq!(fast.add(g, g), reference.double(g));
+        assert_eq!(fast.add(&Point::Identity, g), *g);
+        assert_eq!(fast.add(g, &Point::Identity), *g);
+        assert_eq!(fast.double(&Point::Identity), Point::Identity);
+        assert_eq!(fast.mul(g, &BigUint::from(0u32)), Point::Identity);
+        assert_eq!(
+            fast.mul(&Point::Identity, &BigUint::from(5u32)),
+            Point::Identity
+        );
+
+        let off_curve = Point::Coordinates(BigUint::from(1u32), BigUint::from(1u32));
+        assert!(!fast.is_on_curve(&off_curve));
+    }
+}
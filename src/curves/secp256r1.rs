@@ -0,0 +1,129 @@
+use crate::ec::WeierstrassCurve;
+use crate::point::Point;
+use num_bigint::BigUint;
+
+/// Returns the secp256r1 (NIST P-256) curve parameters
+pub fn create_secp256r1_weierstrass() -> WeierstrassCurve {
+    let p = BigUint::parse_bytes(
+        b"FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFF",
+        16,
+    )
+    .unwrap();
+    // a = -3 mod p
+    let a = &p - BigUint::from(3u32);
+    let b = BigUint::parse_bytes(
+        b"5AC635D8AA3A93E7B3EBBD55769886BC651D06B0CC53B0F63BCE3C3E27D2604B",
+        16,
+    )
+    .unwrap();
+
+    let x = BigUint::parse_bytes(
+        b"6B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296",
+        16,
+    )
+    .unwrap();
+    let y = BigUint::parse_bytes(
+        b"4FE342E2FE1A7F9B8EE7EB4A7C0F9E162BCE33576B315ECECBB6406837BF51F5",
+        16,
+    )
+    .unwrap();
+    let g = Point::Coordinates(x, y);
+    let n = BigUint::parse_bytes(
+        b"FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551",
+        16,
+    )
+    .unwrap();
+
+    WeierstrassCurve::new(a, b, p, n, g).with_cofactor(BigUint::from(1u32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ec::EllipticCurve;
+    use crate::encoding::decode_hex;
+
+    #[test]
+    fn test_secp256r1_params() {
+        let curve = create_secp256r1_weierstrass();
+
+        assert!(curve.is_on_curve(curve.base_point()));
+        assert_eq!(
+            curve.mul(curve.base_point(), curve.order()),
+            Point::Identity
+        );
+        // p ≡ 3 (mod 4), so square roots are a single exponentiation
+        assert_eq!(curve.field_modulus() % 4u32, BigUint::from(3u32));
+    }
+
+    mod decompression {
+        use super::*;
+
+        /// (compressed, x, y): the base point, 2G, 3G and the RFC 6979 A.2.5
+        /// public key
+        const VECTORS: [(&str, &str, &str); 4] = [
+            (
+                "036B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296",
+                "6B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296",
+                "4FE342E2FE1A7F9B8EE7EB4A7C0F9E162BCE33576B315ECECBB6406837BF51F5",
+            ),
+            (
+                "037CF27B188D034F7E8A52380304B51AC3C08969E277F21B35A60B48FC47669978",
+                "7CF27B188D034F7E8A52380304B51AC3C08969E277F21B35A60B48FC47669978",
+                "7775510DB8ED040293D9AC69F7430DBBA7DADE63CE982299E04B79D227873D1",
+            ),
+            (
+                "025ECBE4D1A6330A44C8F7EF951D4BF165E6C6B721EFADA985FB41661BC6E7FD6C",
+                "5ECBE4D1A6330A44C8F7EF951D4BF165E6C6B721EFADA985FB41661BC6E7FD6C",
+                "8734640C4998FF7E374B06CE1A64A2ECD82AB036384FB83D9A79B127A27D5032",
+            ),
+            (
+                "0360FED4BA255A9D31C961EB74C6356D68C049B8923B61FA6CE669622E60F29FB6",
+                "60FED4BA255A9D31C961EB74C6356D68C049B8923B61FA6CE669622E60F29FB6",
+                "7903FE1008B8BC99A41AE9E95628BC64F2F1B20C2D7E9F5177A3C294D4462299",
+            ),
+        ];
+
+        #[test]
+        fn test_published_keys_decompress() {
+            let curve = create_secp256r1_weierstrass();
+            for (compressed, x, y) in VECTORS {
+                let point = curve
+                    .from_sec1_compressed(&decode_hex(compressed).unwrap())
+                    .unwrap();
+                assert_eq!(point, Point::from_hex(x, y).unwrap(), "{}", compressed);
+                assert!(curve.is_on_curve(&point));
+            }
+        }
+
+        #[test]
+        fn test_flipped_parity_gives_negated_point() {
+            let curve = create_secp256r1_weierstrass();
+            for (compressed, x, y) in VECTORS {
+                let mut bytes = decode_hex(compressed).unwrap();
+                bytes[0] ^= 1;
+
+                let point = curve.from_sec1_compressed(&bytes).unwrap();
+                let y = BigUint::parse_bytes(y.as_bytes(), 16).unwrap();
+                assert_eq!(
+                    point,
+                    Point::Coordinates(
+                        BigUint::parse_bytes(x.as_bytes(), 16).unwrap(),
+                        curve.field_modulus() - y
+                    )
+                );
+                assert!(curve.is_on_curve(&point));
+            }
+        }
+
+        #[test]
+        fn test_round_trip() {
+            let curve = create_secp256r1_weierstrass();
+            for (compressed, _, _) in VECTORS {
+                let bytes = decode_hex(compressed).unwrap();
+                let point = curve.from_sec1_compressed(&bytes).unwrap();
+                assert_eq!(curve.to_sec1_compressed(&point), bytes);
+            }
+        }
+    }
+}
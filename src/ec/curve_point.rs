@@ -0,0 +1,127 @@
+use crate::ec::{EllipticCurve, WeierstrassCurve};
+use crate::point::Point;
+use core::fmt;
+use core::ops::{Add, Deref, Mul};
+use num_bigint::BigUint;
+
+/// A `Point` bundled with the curve it lives on, so the group law can be
+/// written with operators: `(a + b) * &k`.
+///
+/// Both operands of `+` are expected to come from the same curve; the
+/// left-hand curve is used for the result.
+#[derive(Clone)]
+pub struct CurvePoint<'a> {
+    point: Point,
+    curve: &'a WeierstrassCurve,
+}
+
+impl<'a> CurvePoint<'a> {
+    pub fn new(point: Point, curve: &'a WeierstrassCurve) -> Self {
+        CurvePoint { point, curve }
+    }
+
+    /// Wraps the base point of `curve`
+    pub fn base_point(curve: &'a WeierstrassCurve) -> Self {
+        Self::new(curve.base_point().clone(), curve)
+    }
+
+    pub fn curve(&self) -> &'a WeierstrassCurve {
+        self.curve
+    }
+
+    pub fn double(&self) -> Self {
+        Self::new(self.curve.double(&self.point), self.curve)
+    }
+
+    /// Unwraps the bare point
+    pub fn into_point(self) -> Point {
+        self.point
+    }
+}
+
+impl Deref for CurvePoint<'_> {
+    type Target = Point;
+
+    fn deref(&self) -> &Point {
+        &self.point
+    }
+}
+
+impl fmt::Debug for CurvePoint<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CurvePoint").field(&self.point).finish()
+    }
+}
+
+impl PartialEq for CurvePoint<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.point == other.point
+    }
+}
+
+impl<'a> Add<&CurvePoint<'a>> for &CurvePoint<'a> {
+    type Output = CurvePoint<'a>;
+
+    fn add(self, rhs: &CurvePoint<'a>) -> CurvePoint<'a> {
+        debug_assert!(core::ptr::eq(self.curve, rhs.curve));
+        CurvePoint::new(self.curve.add(&self.point, &rhs.point), self.curve)
+    }
+}
+
+impl<'a> Add for CurvePoint<'a> {
+    type Output = CurvePoint<'a>;
+
+    fn add(self, rhs: CurvePoint<'a>) -> CurvePoint<'a> {
+        &self + &rhs
+    }
+}
+
+impl<'a> Mul<&BigUint> for &CurvePoint<'a> {
+    type Output = CurvePoint<'a>;
+
+    fn mul(self, scalar: &BigUint) -> CurvePoint<'a> {
+        CurvePoint::new(self.curve.mul(&self.point, scalar), self.curve)
+    }
+}
+
+impl<'a> Mul<&BigUint> for CurvePoint<'a> {
+    type Output = CurvePoint<'a>;
+
+    fn mul(self, scalar: &BigUint) -> CurvePoint<'a> {
+        &self * scalar
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curves::secp256k1::create_secp256k1_weierstrass;
+
+    #[test]
+    fn test_operators_match_curve_methods() {
+        let curve = create_secp256k1_weierstrass();
+        let g = CurvePoint::base_point(&curve);
+
+        assert_eq!(&g + &g, g.double());
+        assert_eq!(*(&g + &g), curve.double(curve.base_point()));
+
+        let k = BigUint::from(12345u32);
+        let expected = curve.mul(curve.base_point(), &(&k * 3u32));
+        assert_eq!(
+            (g.clone() + g.double()) * &k,
+            CurvePoint::new(expected, &curve)
+        );
+    }
+
+    #[test]
+    fn test_identity_and_deref() {
+        let curve = create_secp256k1_weierstrass();
+        let g = CurvePoint::base_point(&curve);
+        let identity = &g * curve.order();
+
+        assert!(identity.is_identity());
+        assert_eq!(&identity + &g, g);
+        assert!(g.is_on_curve(g.curve()));
+        assert_eq!(g.into_point(), *curve.base_point());
+    }
+}
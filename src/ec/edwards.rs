@@ -0,0 +1,210 @@
+use crate::ec::EllipticCurve;
+use crate::ff::FiniteField;
+use crate::point::Point;
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+/// Represents a curve in twisted Edwards form: ax^2 + y^2 = 1 + dx^2y^2
+///
+/// The addition law is unified and, when `a` is a square and `d` is not, complete:
+/// the same formula handles doubling, inverses and the neutral element (0, 1).
+pub struct EdwardsCurve {
+    a: BigUint,
+    d: BigUint,
+    p: BigUint,
+    n: BigUint,
+    h: Option<BigUint>,
+    g: Point,
+}
+
+impl EllipticCurve for EdwardsCurve {
+    /// Adds with the unified formula
+    /// (x3, y3) = ((x1y2 + y1x2) / (1 + dx1x2y1y2), (y1y2 - ax1x2) / (1 - dx1x2y1y2)).
+    ///
+    /// `Point::Identity` enters the formula as (0, 1), and a result of (0, 1) is
+    /// returned as `Point::Identity`.
+    fn add(&self, p1: &Point, p2: &Point) -> Point {
+        let p = &self.p;
+        let (x1, y1) = self.to_affine(p1);
+        let (x2, y2) = self.to_affine(p2);
+
+        let x1x2 = FiniteField::mul(&x1, &x2, p);
+        let y1y2 = FiniteField::mul(&y1, &y2, p);
+        let t = FiniteField::mul(&self.d, &FiniteField::mul(&x1x2, &y1y2, p), p);
+
+        let x_numerator = FiniteField::add(
+            &FiniteField::mul(&x1, &y2, p),
+            &FiniteField::mul(&y1, &x2, p),
+            p,
+        );
+        let y_numerator = FiniteField::sub(&y1y2, &FiniteField::mul(&self.a, &x1x2, p), p);
+        let x3 = FiniteField::div(&x_numerator, &FiniteField::add(&BigUint::one(), &t, p), p);
+        let y3 = FiniteField::div(&y_numerator, &FiniteField::sub(&BigUint::one(), &t, p), p);
+
+        Self::from_affine(x3, y3)
+    }
+
+    fn double(&self, p: &Point) -> Point {
+        self.add(p, p)
+    }
+
+    fn mul(&self, p: &Point, scalar: &BigUint) -> Point {
+        let mut result = Point::Identity;
+        let mut temp = p.clone();
+
+        for i in 0..scalar.bits() {
+            if scalar.bit(i) {
+                result = self.add(&result, &temp);
+            }
+            temp = self.double(&temp);
+        }
+
+        result
+    }
+
+    fn is_on_curve(&self, p: &Point) -> bool {
+        match p {
+            Point::Coordinates(x, y) => {
+                let m = &self.p;
+                let x2 = FiniteField::mul(x, x, m);
+                let y2 = FiniteField::mul(y, y, m);
+                let lhs = FiniteField::add(&FiniteField::mul(&self.a, &x2, m), &y2, m);
+                let dx2y2 = FiniteField::mul(&self.d, &FiniteField::mul(&x2, &y2, m), m);
+                lhs == FiniteField::add(&BigUint::one(), &dx2y2, m)
+            }
+            Point::Identity => true,
+        }
+    }
+
+    fn order(&self) -> &BigUint {
+        &self.n
+    }
+
+    fn cofactor(&self) -> Option<&BigUint> {
+        self.h.as_ref()
+    }
+
+    fn base_point(&self) -> &Point {
+        &self.g
+    }
+
+    fn field_modulus(&self) -> &BigUint {
+        &self.p
+    }
+}
+
+impl EdwardsCurve {
+    pub fn new(a: BigUint, d: BigUint, p: BigUint, n: BigUint, g: Point) -> Self {
+        EdwardsCurve {
+            a,
+            d,
+            p,
+            n,
+            h: None,
+            g,
+        }
+    }
+
+    /// Sets the cofactor, which is unknown otherwise
+    pub fn with_cofactor(mut self, h: BigUint) -> Self {
+        self.h = Some(h);
+        self
+    }
+
+    /// Returns the inverse of a point, (-x, y)
+    pub fn negate(&self, p: &Point) -> Point {
+        match p {
+            Point::Identity => Point::Identity,
+            Point::Coordinates(x, y) => {
+                Self::from_affine(FiniteField::inv_add(x, &self.p), y.clone())
+            }
+        }
+    }
+
+    fn to_affine(&self, p: &Point) -> (BigUint, BigUint) {
+        match p {
+            Point::Identity => (BigUint::zero(), BigUint::one()),
+            Point::Coordinates(x, y) => (x.clone(), y.clone()),
+        }
+    }
+
+    fn from_affine(x: BigUint, y: BigUint) -> Point {
+        if x.is_zero() && y.is_one() {
+            Point::Identity
+        } else {
+            Point::Coordinates(x, y)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curves::ed25519::create_ed25519;
+
+    // -x^2 + y^2 = 1 + 2x^2y^2 over F_13, with a base point of order 16
+    fn create_test_curve() -> EdwardsCurve {
+        EdwardsCurve::new(
+            BigUint::from(12u32),
+            BigUint::from(2u32),
+            BigUint::from(13u32),
+            BigUint::from(16u32),
+            Point::Coordinates(BigUint::from(2u32), BigUint::from(4u32)),
+        )
+    }
+
+    #[test]
+    fn test_base_point_on_curve() {
+        let curve = create_ed25519();
+        assert!(curve.is_on_curve(curve.base_point()));
+        assert_eq!(
+            curve.mul(curve.base_point(), curve.order()),
+            Point::Identity
+        );
+    }
+
+    #[test]
+    fn test_unified_addition_edge_cases() {
+        let curve = create_test_curve();
+        let g = curve.base_point();
+        let neg_g = curve.negate(g);
+
+        assert!(curve.is_on_curve(&neg_g));
+        assert_eq!(curve.add(g, &neg_g), Point::Identity);
+        assert_eq!(curve.add(g, &Point::Identity), *g);
+        assert_eq!(curve.add(&Point::Identity, g), *g);
+        assert_eq!(
+            curve.add(&Point::Identity, &Point::Identity),
+            Point::Identity
+        );
+
+        let curve = create_ed25519();
+        let g = curve.base_point();
+        assert_eq!(curve.add(g, &curve.negate(g)), Point::Identity);
+        assert_eq!(curve.add(g, &Point::Identity), *g);
+    }
+
+    #[test]
+    fn test_mul_matches_repeated_addition() {
+        let curve = create_test_curve();
+        let g = curve.base_point();
+        let mut expected = Point::Identity;
+
+        for k in 0u32..40 {
+            assert_eq!(curve.mul(g, &BigUint::from(k)), expected, "k = {}", k);
+            assert!(curve.is_on_curve(&expected));
+            expected = curve.add(&expected, g);
+        }
+        assert_eq!(curve.mul(g, curve.order()), Point::Identity);
+    }
+
+    #[test]
+    fn test_double_matches_add() {
+        let curve = create_ed25519();
+        let g = curve.base_point();
+        let g2 = curve.double(g);
+
+        assert!(curve.is_on_curve(&g2));
+        assert_eq!(curve.add(&g2, g), curve.mul(g, &BigUint::from(3u32)));
+    }
+}
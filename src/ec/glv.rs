@@ -0,0 +1,55 @@
+use crate::ff::FiniteField;
+use crate::point::Point;
+use num_bigint::{BigInt, BigUint, Sign};
+
+/// An efficiently computable endomorphism φ(x, y) = (βx, y) that acts on the
+/// prime-order subgroup as multiplication by λ, with a reduced basis of the
+/// lattice {(a, b) : a + bλ ≡ 0 (mod n)} for splitting scalars (GLV method).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Endomorphism {
+    pub beta: BigUint,
+    pub lambda: BigUint,
+    pub b1: (BigInt, BigInt),
+    pub b2: (BigInt, BigInt),
+}
+
+impl Endomorphism {
+    /// Applies φ to a point
+    pub(crate) fn apply(&self, point: &Point, p: &BigUint) -> Point {
+        match point {
+            Point::Identity => Point::Identity,
+            Point::Coordinates(x, y) => {
+                Point::Coordinates(FiniteField::mul(&self.beta, x, p), y.clone())
+            }
+        }
+    }
+
+    /// Splits `k` into `(k1, k2)` with `k ≡ k1 + k2·λ (mod n)`, both of about
+    /// half the bit length of `n`.
+    ///
+    /// Rounds `k` to the nearest lattice point `c1·b1 + c2·b2` and returns the
+    /// difference, where `(c1, c2)` solves `(k, 0) = c1·b1 + c2·b2` over the
+    /// rationals.
+    pub(crate) fn decompose(&self, k: &BigUint, n: &BigUint) -> (BigInt, BigInt) {
+        let (a1, b1) = &self.b1;
+        let (a2, b2) = &self.b2;
+        let k = BigInt::from(k.clone());
+        let n = BigInt::from(n.clone());
+
+        let c1 = round_div(&(b2 * &k), &n);
+        let c2 = round_div(&(-b1 * &k), &n);
+
+        let k1 = &k - &c1 * a1 - &c2 * a2;
+        let k2 = -(&c1 * b1) - &c2 * b2;
+        (k1, k2)
+    }
+}
+
+/// `a / n` rounded to the nearest integer, for `n > 0`
+fn round_div(a: &BigInt, n: &BigInt) -> BigInt {
+    let half: BigInt = n / 2u32;
+    match a.sign() {
+        Sign::Minus => -((&half - a) / n),
+        _ => (a + &half) / n,
+    }
+}
@@ -0,0 +1,140 @@
+use crate::ff::FiniteField;
+use crate::montgomery_field::MontgomeryField;
+use crate::point::Point;
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+/// A point in Jacobian coordinates `(X, Y, Z)` standing for `(X/Z², Y/Z³)`,
+/// with every coordinate in Montgomery form; `Z = 0` is the point at infinity
+#[derive(Clone)]
+struct Jacobian {
+    x: BigUint,
+    y: BigUint,
+    z: BigUint,
+}
+
+/// Scalar multiplication on y^2 = x^3 + ax + b with Jacobian coordinates over
+/// a `MontgomeryField`. The only inversion is the one converting the result
+/// back to affine coordinates.
+pub(crate) struct JacobianMul {
+    field: MontgomeryField,
+    p: BigUint,
+    /// `a` in Montgomery form
+    a: BigUint,
+    one: BigUint,
+}
+
+impl JacobianMul {
+    pub(crate) fn new(a: &BigUint, p: &BigUint) -> Self {
+        let field = MontgomeryField::new(p);
+        JacobianMul {
+            a: field.encode(a),
+            one: field.encode(&BigUint::from(1u32)),
+            field,
+            p: p.clone(),
+        }
+    }
+
+    pub(crate) fn mul(&self, point: &Point, scalar: &BigUint) -> Point {
+        let base = match point {
+            Point::Identity => return Point::Identity,
+            Point::Coordinates(x, y) => Jacobian {
+                x: self.field.encode(x),
+                y: self.field.encode(y),
+                z: self.one.clone(),
+            },
+        };
+
+        let mut result = self.infinity();
+        for i in (0..scalar.bits()).rev() {
+            result = self.double(&result);
+            if scalar.bit(i) {
+                result = self.add(&result, &base);
+            }
+        }
+        self.to_affine(&result)
+    }
+
+    fn infinity(&self) -> Jacobian {
+        Jacobian {
+            x: self.one.clone(),
+            y: self.one.clone(),
+            z: BigUint::zero(),
+        }
+    }
+
+    fn to_affine(&self, q: &Jacobian) -> Point {
+        if q.z.is_zero() {
+            return Point::Identity;
+        }
+        let p = &self.p;
+        let z_inv = FiniteField::inv_mul(&self.field.decode(&q.z), p);
+        let z_inv2 = FiniteField::mul(&z_inv, &z_inv, p);
+        let z_inv3 = FiniteField::mul(&z_inv2, &z_inv, p);
+        Point::Coordinates(
+            FiniteField::mul(&self.field.decode(&q.x), &z_inv2, p),
+            FiniteField::mul(&self.field.decode(&q.y), &z_inv3, p),
+        )
+    }
+
+    // dbl-2007-bl
+    fn double(&self, q: &Jacobian) -> Jacobian {
+        let f = &self.field;
+        if q.z.is_zero() || q.y.is_zero() {
+            return self.infinity();
+        }
+        let xx = f.square(&q.x);
+        let yy = f.square(&q.y);
+        let yyyy = f.square(&yy);
+        let zz = f.square(&q.z);
+        let s = f.mul_small(&f.sub(&f.sub(&f.square(&f.add(&q.x, &yy)), &xx), &yyyy), 2);
+        let m = f.add(&f.mul_small(&xx, 3), &f.mul(&self.a, &f.square(&zz)));
+        let x3 = f.sub(&f.square(&m), &f.mul_small(&s, 2));
+        let y3 = f.sub(&f.mul(&m, &f.sub(&s, &x3)), &f.mul_small(&yyyy, 8));
+        let z3 = f.sub(&f.sub(&f.square(&f.add(&q.y, &q.z)), &yy), &zz);
+        Jacobian {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    // add-2007-bl without the Z = 1 shortcuts
+    fn add(&self, q: &Jacobian, r: &Jacobian) -> Jacobian {
+        let f = &self.field;
+        if q.z.is_zero() {
+            return r.clone();
+        }
+        if r.z.is_zero() {
+            return q.clone();
+        }
+        let z1z1 = f.square(&q.z);
+        let z2z2 = f.square(&r.z);
+        let u1 = f.mul(&q.x, &z2z2);
+        let u2 = f.mul(&r.x, &z1z1);
+        let s1 = f.mul(&f.mul(&q.y, &r.z), &z2z2);
+        let s2 = f.mul(&f.mul(&r.y, &q.z), &z1z1);
+        let h = f.sub(&u2, &u1);
+        let rr = f.sub(&s2, &s1);
+
+        if h.is_zero() {
+            return if rr.is_zero() {
+                self.double(q)
+            } else {
+                self.infinity()
+            };
+        }
+
+        let hh = f.square(&h);
+        let hhh = f.mul(&h, &hh);
+        let v = f.mul(&u1, &hh);
+        let x3 = f.sub(&f.sub(&f.square(&rr), &hhh), &f.mul_small(&v, 2));
+        let y3 = f.sub(&f.mul(&rr, &f.sub(&v, &x3)), &f.mul(&s1, &hhh));
+        let z3 = f.mul(&f.mul(&q.z, &r.z), &h);
+        Jacobian {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+}
@@ -1,8 +1,23 @@
 use crate::point::Point;
 use num_bigint::BigUint;
+use num_traits::One;
 
+mod curve_point;
+pub mod edwards;
+mod glv;
+mod jacobian;
+pub mod montgomery;
+#[cfg(feature = "serde")]
+mod params;
+mod poly;
 pub mod weierstrass;
-pub use weierstrass::WeierstrassCurve;
+pub use curve_point::CurvePoint;
+pub use edwards::EdwardsCurve;
+pub use glv::Endomorphism;
+pub use montgomery::MontgomeryCurve;
+#[cfg(feature = "serde")]
+pub use params::CurveParams;
+pub use weierstrass::{AddKind, WeierstrassCurve};
 
 /// Base trait for all elliptic curves
 pub trait EllipticCurve {
@@ -11,6 +26,104 @@ pub trait EllipticCurve {
   fn mul(&self, p: &Point, scalar: &BigUint) -> Point;
   fn is_on_curve(&self, p: &Point) -> bool;
   fn order(&self) -> &BigUint;
+  /// Returns the cofactor `h = #E / order()`, or `None` if it is unknown
+  fn cofactor(&self) -> Option<&BigUint> {
+    None
+  }
   fn base_point(&self) -> &Point;
   fn field_modulus(&self) -> &BigUint;
-}
\ No newline at end of file
+
+  /// Multiplies the base point by a scalar.
+  ///
+  /// Curves that keep precomputed multiples of the base point override this.
+  fn mul_base(&self, scalar: &BigUint) -> Point {
+    self.mul(self.base_point(), scalar)
+  }
+
+  /// Reduces a scalar modulo the curve order
+  fn reduce_scalar(&self, s: &BigUint) -> BigUint {
+    s % self.order()
+  }
+
+  /// Returns `-s` modulo the curve order, reducing `s` first
+  fn negate_scalar(&self, s: &BigUint) -> BigUint {
+    (self.order() - self.reduce_scalar(s)) % self.order()
+  }
+
+  /// Multiplies a point by a scalar reduced modulo the curve order.
+  ///
+  /// Only valid for points in the prime-order subgroup, where `k·P == (k mod n)·P`.
+  fn mul_reduced(&self, p: &Point, scalar: &BigUint) -> Point {
+    self.mul(p, &self.reduce_scalar(scalar))
+  }
+
+  /// Computes `s1·p1 + s2·p2` with Shamir's trick.
+  ///
+  /// Both scalars are processed in a single left-to-right pass that shares the
+  /// doublings, adding `p1`, `p2` or the precomputed `p1 + p2` per bit pair.
+  fn mul_double(&self, p1: &Point, s1: &BigUint, p2: &Point, s2: &BigUint) -> Point {
+    let sum = self.add(p1, p2);
+    let mut result = Point::Identity;
+
+    for i in (0..s1.bits().max(s2.bits())).rev() {
+      result = self.double(&result);
+      match (s1.bit(i), s2.bit(i)) {
+        (true, true) => result = self.add(&result, &sum),
+        (true, false) => result = self.add(&result, p1),
+        (false, true) => result = self.add(&result, p2),
+        (false, false) => {}
+      }
+    }
+
+    result
+  }
+
+  /// Multiplies a point by the cofactor, mapping it into the prime-order
+  /// subgroup, or returns `None` if the cofactor is unknown
+  fn clear_cofactor(&self, p: &Point) -> Option<Point> {
+    self.cofactor().map(|h| self.mul(p, h))
+  }
+
+  /// Checks that a point lies on the curve and in the prime-order subgroup,
+  /// i.e. `order()·P` is the identity. Points from small subgroups fail this
+  /// check on curves with a cofactor above 1.
+  ///
+  /// With a cofactor of 1 the subgroup is the whole curve, so the scalar
+  /// multiplication is skipped; an unknown cofactor gets the full check.
+  fn is_in_subgroup(&self, p: &Point) -> bool {
+    if self.cofactor().is_some_and(One::is_one) {
+      return self.is_on_curve(p);
+    }
+    self.is_on_curve(p) && self.mul(p, self.order()) == Point::Identity
+  }
+
+  /// Adds two points and reports whether the sum is the point at infinity
+  fn add_detect_identity(&self, p1: &Point, p2: &Point) -> (Point, bool) {
+    let sum = self.add(p1, p2);
+    let is_identity = sum == Point::Identity;
+    (sum, is_identity)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::curves::secp256k1::create_secp256k1_weierstrass;
+  use num_traits::Zero;
+
+  #[test]
+  fn test_negate_scalar() {
+    let secp256k1 = create_secp256k1_weierstrass();
+    let n = secp256k1.order();
+    let s = BigUint::parse_bytes(b"C0FFEE0123456789ABCDEF", 16).unwrap();
+
+    for s in [s.clone(), &s + n, BigUint::from(1u32), n - 1u32] {
+      let neg = secp256k1.negate_scalar(&s);
+      assert!(&neg < n);
+      assert!(((neg + &s) % n).is_zero());
+    }
+    assert!(secp256k1.negate_scalar(&BigUint::zero()).is_zero());
+    assert!(secp256k1.negate_scalar(n).is_zero());
+    assert_eq!(secp256k1.negate_scalar(&s), n - &s);
+  }
+}
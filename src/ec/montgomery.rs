@@ -0,0 +1,541 @@
+use crate::ec::{EllipticCurve, WeierstrassCurve};
+use crate::ff::FiniteField;
+use crate::point::Point;
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+/// Represents a curve in Montgomery form: By^2 = x^3 + Ax^2 + x
+pub struct MontgomeryCurve {
+    a: BigUint,
+    b: BigUint,
+    p: BigUint,
+    n: BigUint,
+    h: Option<BigUint>,
+    g: Point,
+}
+
+impl EllipticCurve for MontgomeryCurve {
+    fn add(&self, p1: &Point, p2: &Point) -> Point {
+        match (p1, p2) {
+            (Point::Identity, _) => p2.clone(),
+            (_, Point::Identity) => p1.clone(),
+            (Point::Coordinates(x1, y1), Point::Coordinates(x2, y2)) => {
+                if x1 == x2 {
+                    if FiniteField::add(y1, y2, &self.p).is_zero() {
+                        return Point::Identity;
+                    }
+                    if y1 == y2 {
+                        return self.double(p1);
+                    }
+                }
+                let numerator = FiniteField::sub(y2, y1, &self.p);
+                let denominator = FiniteField::sub(x2, x1, &self.p);
+                let s = FiniteField::div(&numerator, &denominator, &self.p);
+                self.chord_point(&s, x1, y1, x2)
+            }
+        }
+    }
+
+    fn double(&self, p: &Point) -> Point {
+        match p {
+            Point::Identity => Point::Identity,
+            // points with y = 0 have order two
+            Point::Coordinates(_, y) if y.is_zero() => Point::Identity,
+            Point::Coordinates(x, y) => {
+                let s = self.calculate_tangent_slope(x, y);
+                self.chord_point(&s, x, y, x)
+            }
+        }
+    }
+
+    /// Multiplies with the x-only Montgomery ladder, then recovers y from the
+    /// ladder's two outputs `k·P` and `(k+1)·P`.
+    fn mul(&self, p: &Point, scalar: &BigUint) -> Point {
+        let (x, y) = match p {
+            Point::Identity => return Point::Identity,
+            Point::Coordinates(x, y) => (x, y),
+        };
+
+        // the ladder's differential addition needs x(P) != 0, which only fails
+        // for the order-two point (0, 0)
+        if y.is_zero() {
+            return if scalar.bit(0) {
+                p.clone()
+            } else {
+                Point::Identity
+            };
+        }
+
+        let ((x_k, z_k), (x_k1, z_k1)) = self.ladder(x, scalar);
+        if z_k.is_zero() {
+            return Point::Identity;
+        }
+        let x_q = FiniteField::div(&x_k, &z_k, &self.p);
+        if z_k1.is_zero() {
+            // (k+1)·P is the identity, so k·P = -P
+            return Point::Coordinates(x_q, FiniteField::inv_add(y, &self.p));
+        }
+        let x_q1 = FiniteField::div(&x_k1, &z_k1, &self.p);
+
+        Point::Coordinates(x_q.clone(), self.recover_y(x, y, &x_q, &x_q1))
+    }
+
+    fn is_on_curve(&self, p: &Point) -> bool {
+        match p {
+            Point::Coordinates(x, y) => {
+                let by2 = FiniteField::mul(&self.b, &FiniteField::mul(y, y, &self.p), &self.p);
+                by2 == self.curve_rhs(x)
+            }
+            Point::Identity => true,
+        }
+    }
+
+    fn order(&self) -> &BigUint {
+        &self.n
+    }
+
+    fn cofactor(&self) -> Option<&BigUint> {
+        self.h.as_ref()
+    }
+
+    fn base_point(&self) -> &Point {
+        &self.g
+    }
+
+    fn field_modulus(&self) -> &BigUint {
+        &self.p
+    }
+}
+
+/// The x-only steps the Montgomery ladder is built from. Keeping them behind a
+/// trait lets the tests count the steps a ladder run performs.
+trait LadderStep {
+    fn x_add(
+        &self,
+        q: &(BigUint, BigUint),
+        r: &(BigUint, BigUint),
+        x_diff: &BigUint,
+    ) -> (BigUint, BigUint);
+    fn x_double(&self, q: &(BigUint, BigUint)) -> (BigUint, BigUint);
+}
+
+/// Runs the Montgomery ladder with the given steps over the low `bits` bits
+/// of `scalar`; see `MontgomeryCurve::ladder`
+fn ladder<S: LadderStep>(
+    steps: &S,
+    x: &BigUint,
+    scalar: &BigUint,
+    bits: u64,
+) -> ((BigUint, BigUint), (BigUint, BigUint)) {
+    let mut r0 = (BigUint::from(1u32), BigUint::zero());
+    let mut r1 = (x.clone(), BigUint::from(1u32));
+
+    for i in (0..bits).rev() {
+        if scalar.bit(i) {
+            r0 = steps.x_add(&r0, &r1, x);
+            r1 = steps.x_double(&r1);
+        } else {
+            r1 = steps.x_add(&r0, &r1, x);
+            r0 = steps.x_double(&r0);
+        }
+    }
+
+    (r0, r1)
+}
+
+impl LadderStep for MontgomeryCurve {
+    /// Differential addition: x(Q + R) from x(Q), x(R) and x(Q - R)
+    fn x_add(
+        &self,
+        q: &(BigUint, BigUint),
+        r: &(BigUint, BigUint),
+        x_diff: &BigUint,
+    ) -> (BigUint, BigUint) {
+        let p = &self.p;
+        let da = FiniteField::mul(
+            &FiniteField::sub(&q.0, &q.1, p),
+            &FiniteField::add(&r.0, &r.1, p),
+            p,
+        );
+        let cb = FiniteField::mul(
+            &FiniteField::add(&q.0, &q.1, p),
+            &FiniteField::sub(&r.0, &r.1, p),
+            p,
+        );
+        let sum = FiniteField::add(&da, &cb, p);
+        let diff = FiniteField::sub(&da, &cb, p);
+        (
+            FiniteField::mul(&sum, &sum, p),
+            FiniteField::mul(x_diff, &FiniteField::mul(&diff, &diff, p), p),
+        )
+    }
+
+    /// x-only doubling with a24 = (A + 2) / 4
+    fn x_double(&self, q: &(BigUint, BigUint)) -> (BigUint, BigUint) {
+        let p = &self.p;
+        let sum = FiniteField::add(&q.0, &q.1, p);
+        let diff = FiniteField::sub(&q.0, &q.1, p);
+        let sum2 = FiniteField::mul(&sum, &sum, p);
+        let diff2 = FiniteField::mul(&diff, &diff, p);
+        let e = FiniteField::sub(&sum2, &diff2, p);
+        let a24 = FiniteField::div(
+            &FiniteField::add(&self.a, &BigUint::from(2u32), p),
+            &BigUint::from(4u32),
+            p,
+        );
+        (
+            FiniteField::mul(&sum2, &diff2, p),
+            FiniteField::mul(
+                &e,
+                &FiniteField::add(&diff2, &FiniteField::mul(&a24, &e, p), p),
+                p,
+            ),
+        )
+    }
+}
+
+impl MontgomeryCurve {
+    pub fn new(a: BigUint, b: BigUint, p: BigUint, n: BigUint, g: Point) -> Self {
+        MontgomeryCurve {
+            a,
+            b,
+            p,
+            n,
+            h: None,
+            g,
+        }
+    }
+
+    /// Sets the cofactor, which is unknown otherwise
+    pub fn with_cofactor(mut self, h: BigUint) -> Self {
+        self.h = Some(h);
+        self
+    }
+
+    /// Returns the equivalent short Weierstrass curve y^2 = x^3 + ax + b with
+    /// a = (3 - A^2) / 3B^2 and b = (2A^3 - 9A) / 27B^3, together with the
+    /// mapping (x, y) -> (x/B + A/3B, y/B). This inverts
+    /// `WeierstrassCurve::to_montgomery`.
+    pub fn to_weierstrass(&self) -> (WeierstrassCurve, impl Fn(&Point) -> Point) {
+        let p = self.p.clone();
+        let three = BigUint::from(3u32);
+        let a2 = FiniteField::mul(&self.a, &self.a, &p);
+        let a3 = FiniteField::mul(&a2, &self.a, &p);
+        let b2 = FiniteField::mul(&self.b, &self.b, &p);
+        let b3 = FiniteField::mul(&b2, &self.b, &p);
+
+        let a = FiniteField::div(
+            &FiniteField::sub(&three, &a2, &p),
+            &FiniteField::mul(&three, &b2, &p),
+            &p,
+        );
+        let b = FiniteField::div(
+            &FiniteField::sub(
+                &FiniteField::mul(&BigUint::from(2u32), &a3, &p),
+                &FiniteField::mul(&BigUint::from(9u32), &self.a, &p),
+                &p,
+            ),
+            &FiniteField::mul(&BigUint::from(27u32), &b3, &p),
+            &p,
+        );
+
+        let b_inv = FiniteField::inv_mul(&self.b, &p);
+        let shift = FiniteField::div(&self.a, &FiniteField::mul(&three, &self.b, &p), &p);
+        let map = move |point: &Point| match point {
+            Point::Identity => Point::Identity,
+            Point::Coordinates(x, y) => Point::Coordinates(
+                FiniteField::add(&FiniteField::mul(x, &b_inv, &p), &shift, &p),
+                FiniteField::mul(y, &b_inv, &p),
+            ),
+        };
+
+        let mut curve = WeierstrassCurve::new(a, b, self.p.clone(), self.n.clone(), map(&self.g));
+        if let Some(h) = &self.h {
+            curve = curve.with_cofactor(h.clone());
+        }
+        (curve, map)
+    }
+
+    /// Computes the x coordinate of `scalar·P` from the x coordinate of `P`
+    /// alone, as used for X25519-style key exchange. Returns `None` when the
+    /// result is the point at infinity.
+    pub fn mul_x(&self, x: &BigUint, scalar: &BigUint) -> Option<BigUint> {
+        let ((x_k, z_k), _) = self.ladder(x, scalar);
+        if z_k.is_zero() {
+            None
+        } else {
+            Some(FiniteField::div(&x_k, &z_k, &self.p))
+        }
+    }
+
+    /// Runs the Montgomery ladder on projective (X : Z) coordinates and returns
+    /// `k·P` and `(k+1)·P`. Every bit performs one differential addition and one
+    /// doubling, whatever its value.
+    fn ladder(&self, x: &BigUint, scalar: &BigUint) -> ((BigUint, BigUint), (BigUint, BigUint)) {
+        ladder(self, x, scalar, self.ladder_bits(scalar))
+    }
+
+    /// The number of ladder iterations: the bit length of `p`, so the step
+    /// count doesn't reveal the scalar's length, or of longer scalars. Leading
+    /// zero bits keep the ladder at (O, P).
+    fn ladder_bits(&self, scalar: &BigUint) -> u64 {
+        self.p.bits().max(scalar.bits())
+    }
+
+    /// Okeya-Sakurai y recovery of Q = k·P from P, x(Q) and x(Q + P):
+    /// y_Q = ((x_P·x_Q + 1)(x_P + x_Q + 2A) - 2A - (x_P - x_Q)^2·x_{Q+P}) / (2B·y_P)
+    fn recover_y(&self, x_p: &BigUint, y_p: &BigUint, x_q: &BigUint, x_q1: &BigUint) -> BigUint {
+        let p = &self.p;
+        let two_a = FiniteField::add(&self.a, &self.a, p);
+        let left = FiniteField::mul(
+            &FiniteField::add(&FiniteField::mul(x_p, x_q, p), &BigUint::from(1u32), p),
+            &FiniteField::add(&FiniteField::add(x_p, x_q, p), &two_a, p),
+            p,
+        );
+        let diff = FiniteField::sub(x_p, x_q, p);
+        let right = FiniteField::mul(&FiniteField::mul(&diff, &diff, p), x_q1, p);
+        let numerator = FiniteField::sub(&FiniteField::sub(&left, &two_a, p), &right, p);
+        let denominator = FiniteField::mul(&FiniteField::add(&self.b, &self.b, p), y_p, p);
+        FiniteField::div(&numerator, &denominator, p)
+    }
+
+    /// Third intersection of the line with slope `s` through (x1, y1), reflected
+    fn chord_point(&self, s: &BigUint, x1: &BigUint, y1: &BigUint, x2: &BigUint) -> Point {
+        let p = &self.p;
+        let bs2 = FiniteField::mul(&self.b, &FiniteField::mul(s, s, p), p);
+        let x3 = FiniteField::sub(
+            &FiniteField::sub(&FiniteField::sub(&bs2, &self.a, p), x1, p),
+            x2,
+            p,
+        );
+        let y3 = FiniteField::sub(
+            &FiniteField::mul(s, &FiniteField::sub(x1, &x3, p), p),
+            y1,
+            p,
+        );
+        Point::Coordinates(x3, y3)
+    }
+
+    fn calculate_tangent_slope(&self, x: &BigUint, y: &BigUint) -> BigUint {
+        let p = &self.p;
+        let x2 = FiniteField::mul(x, x, p);
+        let numerator = FiniteField::add(
+            &FiniteField::add(
+                &FiniteField::mul(&BigUint::from(3u32), &x2, p),
+                &FiniteField::mul(&FiniteField::add(&self.a, &self.a, p), x, p),
+                p,
+            ),
+            &BigUint::from(1u32),
+            p,
+        );
+        let denominator = FiniteField::mul(&FiniteField::add(&self.b, &self.b, p), y, p);
+        FiniteField::div(&numerator, &denominator, p)
+    }
+
+    fn curve_rhs(&self, x: &BigUint) -> BigUint {
+        let p = &self.p;
+        let x2 = FiniteField::mul(x, x, p);
+        let x3 = FiniteField::mul(&x2, x, p);
+        let ax2 = FiniteField::mul(&self.a, &x2, p);
+        FiniteField::add(&FiniteField::add(&x3, &ax2, p), x, p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curves::curve25519::create_curve25519;
+
+    // y^2 = x^3 + 3x^2 + x over F_101, with a base point of order 24
+    fn create_test_curve() -> MontgomeryCurve {
+        MontgomeryCurve::new(
+            BigUint::from(3u32),
+            BigUint::from(1u32),
+            BigUint::from(101u32),
+            BigUint::from(24u32),
+            Point::Coordinates(BigUint::from(24u32), BigUint::from(27u32)),
+        )
+    }
+
+    fn naive_mul(curve: &MontgomeryCurve, p: &Point, scalar: u32) -> Point {
+        (0..scalar).fold(Point::Identity, |acc, _| curve.add(&acc, p))
+    }
+
+    #[test]
+    fn test_base_point_on_curve() {
+        let curve = create_curve25519();
+        assert!(curve.is_on_curve(curve.base_point()));
+        assert_eq!(
+            curve.mul(curve.base_point(), curve.order()),
+            Point::Identity
+        );
+    }
+
+    #[test]
+    fn test_add_and_double_stay_on_curve() {
+        let curve = create_test_curve();
+        let g = curve.base_point();
+        let g2 = curve.double(g);
+        let g3 = curve.add(&g2, g);
+
+        assert!(curve.is_on_curve(&g2));
+        assert!(curve.is_on_curve(&g3));
+        assert_eq!(curve.add(g, &g2), g3);
+    }
+
+    #[test]
+    fn test_ladder_matches_repeated_addition() {
+        let curve = create_test_curve();
+        let g = curve.base_point();
+
+        for k in 0u32..40 {
+            assert_eq!(
+                curve.mul(g, &BigUint::from(k)),
+                naive_mul(&curve, g, k),
+                "mismatch for k = {}",
+                k
+            );
+        }
+        assert_eq!(curve.mul(g, curve.order()), Point::Identity);
+
+        let two_torsion = Point::Coordinates(BigUint::zero(), BigUint::zero());
+        assert_eq!(curve.mul(&two_torsion, &BigUint::from(3u32)), two_torsion);
+        assert_eq!(
+            curve.mul(&two_torsion, &BigUint::from(4u32)),
+            Point::Identity
+        );
+    }
+
+    /// Wraps a curve and records the sequence of ladder steps it performs
+    struct CountingSteps<'a> {
+        curve: &'a MontgomeryCurve,
+        trace: core::cell::RefCell<Vec<&'static str>>,
+    }
+
+    impl LadderStep for CountingSteps<'_> {
+        fn x_add(
+            &self,
+            q: &(BigUint, BigUint),
+            r: &(BigUint, BigUint),
+            x_diff: &BigUint,
+        ) -> (BigUint, BigUint) {
+            self.trace.borrow_mut().push("add");
+            self.curve.x_add(q, r, x_diff)
+        }
+
+        fn x_double(&self, q: &(BigUint, BigUint)) -> (BigUint, BigUint) {
+            self.trace.borrow_mut().push("double");
+            self.curve.x_double(q)
+        }
+    }
+
+    fn ladder_trace(curve: &MontgomeryCurve, scalar: &BigUint) -> Vec<&'static str> {
+        let steps = CountingSteps {
+            curve,
+            trace: Default::default(),
+        };
+        let (x, _) = curve.base_point().coordinates().unwrap();
+        let (result, _) = ladder(&steps, x, scalar, curve.ladder_bits(scalar));
+        assert_eq!(result, curve.ladder(x, scalar).0);
+        steps.trace.into_inner()
+    }
+
+    #[test]
+    fn test_ladder_steps_do_not_depend_on_scalar_bits() {
+        let curve = create_curve25519();
+        // 255-bit scalars with Hamming weight 1 and 255
+        let low_weight = BigUint::from(1u32) << 254;
+        let high_weight = (BigUint::from(1u32) << 255) - 1u32;
+
+        let low = ladder_trace(&curve, &low_weight);
+        let high = ladder_trace(&curve, &high_weight);
+        assert_eq!(low.len(), 2 * 255);
+        assert_eq!(low, high);
+
+        // a typical scalar, with the top bit set as X25519 clamping does
+        let mixed = BigUint::parse_bytes(
+            b"5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb",
+            16,
+        )
+        .unwrap()
+            | &low_weight;
+        assert_eq!(mixed.bits(), 255);
+        assert_eq!(ladder_trace(&curve, &mixed), low);
+
+        // short scalars run the full width too
+        for short in [0u32, 1, 5] {
+            assert_eq!(ladder_trace(&curve, &BigUint::from(short)), low);
+        }
+    }
+
+    #[test]
+    fn test_mul_with_short_scalars() {
+        let curve = create_curve25519();
+        let g = curve.base_point();
+        let mut expected = Point::Identity;
+        for k in 0u32..6 {
+            assert_eq!(curve.mul(g, &BigUint::from(k)), expected, "k = {}", k);
+            expected = curve.add(&expected, g);
+        }
+    }
+
+    #[test]
+    fn test_x25519_style_key_exchange() {
+        let curve = create_curve25519();
+        let u = BigUint::from(9u32);
+        let alice = BigUint::parse_bytes(
+            b"77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a",
+            16,
+        )
+        .unwrap();
+        let bob = BigUint::parse_bytes(
+            b"5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb",
+            16,
+        )
+        .unwrap();
+
+        let alice_public = curve.mul_x(&u, &alice).unwrap();
+        let bob_public = curve.mul_x(&u, &bob).unwrap();
+
+        assert_eq!(
+            curve.mul_x(&bob_public, &alice),
+            curve.mul_x(&alice_public, &bob)
+        );
+
+        let alice_point = curve.mul(curve.base_point(), &alice);
+        assert!(curve.is_on_curve(&alice_point));
+        assert!(matches!(alice_point, Point::Coordinates(x, _) if x == alice_public));
+    }
+
+    #[test]
+    fn test_rfc7748_vector() {
+        // RFC 7748 section 5.2: little-endian inputs, clamped scalar
+        let mut scalar = crate::encoding::decode_hex(
+            "a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4",
+        )
+        .unwrap();
+        scalar[0] &= 248;
+        scalar[31] &= 127;
+        scalar[31] |= 64;
+        let mut u = crate::encoding::decode_hex(
+            "e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c",
+        )
+        .unwrap();
+        u[31] &= 127;
+
+        let curve = create_curve25519();
+        let result = curve
+            .mul_x(
+                &BigUint::from_bytes_le(&u),
+                &BigUint::from_bytes_le(&scalar),
+            )
+            .unwrap();
+        let mut expected = crate::encoding::decode_hex(
+            "c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552",
+        )
+        .unwrap();
+        expected.reverse();
+
+        assert_eq!(result, BigUint::from_bytes_be(&expected));
+    }
+}
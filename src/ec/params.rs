@@ -0,0 +1,128 @@
+use crate::ec::{EllipticCurve, WeierstrassCurve};
+use crate::error::Error;
+use crate::point::Point;
+use alloc::format;
+use alloc::string::String;
+use num_bigint::BigUint;
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Weierstrass curve parameters as upper-case hex strings, the form used to
+/// (de)serialize a `WeierstrassCurve` behind the `serde` feature.
+///
+/// Deserializing a `WeierstrassCurve` directly checks that the base point is on
+/// the curve; deserialize `CurveParams` and call `into_curve(false)` to skip it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CurveParams {
+    pub a: String,
+    pub b: String,
+    pub p: String,
+    pub n: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub h: Option<String>,
+    pub gx: String,
+    pub gy: String,
+}
+
+fn parse_hex(hex: &str) -> Result<BigUint, Error> {
+    BigUint::parse_bytes(hex.as_bytes(), 16).ok_or(Error::InvalidHex)
+}
+
+impl CurveParams {
+    /// Returns the parameters of `curve`, or `None` if its base point is the identity
+    pub fn from_curve(curve: &WeierstrassCurve) -> Option<Self> {
+        let (gx, gy) = curve.base_point().to_hex()?;
+        Some(CurveParams {
+            a: format!("{:X}", curve.a()),
+            b: format!("{:X}", curve.b()),
+            p: format!("{:X}", curve.field_modulus()),
+            n: format!("{:X}", curve.order()),
+            h: curve.cofactor().map(|h| format!("{:X}", h)),
+            gx,
+            gy,
+        })
+    }
+
+    /// Builds the curve, optionally checking that the base point is on it
+    pub fn into_curve(self, validate: bool) -> Result<WeierstrassCurve, Error> {
+        let mut curve = WeierstrassCurve::new(
+            parse_hex(&self.a)?,
+            parse_hex(&self.b)?,
+            parse_hex(&self.p)?,
+            parse_hex(&self.n)?,
+            Point::from_hex(&self.gx, &self.gy)?,
+        );
+        if let Some(h) = &self.h {
+            curve = curve.with_cofactor(parse_hex(h)?);
+        }
+
+        if validate && !curve.is_on_curve(curve.base_point()) {
+            return Err(Error::NotOnCurve);
+        }
+        Ok(curve)
+    }
+}
+
+impl Serialize for WeierstrassCurve {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        CurveParams::from_curve(self)
+            .ok_or_else(|| ser::Error::custom("base point is the point at infinity"))?
+            .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for WeierstrassCurve {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        CurveParams::deserialize(deserializer)?
+            .into_curve(true)
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curves::secp256k1::create_secp256k1_weierstrass;
+
+    #[test]
+    fn test_secp256k1_json_round_trip() {
+        let curve = create_secp256k1_weierstrass();
+        let json = serde_json::to_string(&curve).unwrap();
+        let decoded: WeierstrassCurve = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(decoded.a(), curve.a());
+        assert_eq!(decoded.b(), curve.b());
+        assert_eq!(decoded.field_modulus(), curve.field_modulus());
+        assert_eq!(decoded.order(), curve.order());
+        assert_eq!(decoded.cofactor(), curve.cofactor());
+        assert_eq!(decoded.base_point(), curve.base_point());
+        assert!(json.contains(
+            "\"gx\":\"79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798\""
+        ));
+    }
+
+    #[test]
+    fn test_base_point_validation() {
+        let mut params = CurveParams::from_curve(&create_secp256k1_weierstrass()).unwrap();
+        params.gy = String::from("2");
+        let json = serde_json::to_string(&params).unwrap();
+
+        assert!(serde_json::from_str::<WeierstrassCurve>(&json).is_err());
+        assert_eq!(
+            params.clone().into_curve(true).err(),
+            Some(Error::NotOnCurve)
+        );
+        assert!(params.into_curve(false).is_ok());
+    }
+
+    #[test]
+    fn test_cofactor_defaults_to_unknown() {
+        let json = r#"{"a":"2","b":"2","p":"11","n":"13","gx":"5","gy":"1"}"#;
+        let curve: WeierstrassCurve = serde_json::from_str(json).unwrap();
+        assert_eq!(curve.field_modulus(), &BigUint::from(17u32));
+        assert_eq!(curve.cofactor(), None);
+        assert!(!serde_json::to_string(&curve).unwrap().contains("\"h\""));
+
+        let invalid = json.replace("\"11\"", "\"xyz\"");
+        assert!(serde_json::from_str::<WeierstrassCurve>(&invalid).is_err());
+    }
+}
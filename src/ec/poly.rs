@@ -0,0 +1,164 @@
+use crate::ff::FiniteField;
+use alloc::vec;
+use alloc::vec::Vec;
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+/// A polynomial with coefficients in ascending degree, kept without leading zeros
+type Poly = Vec<BigUint>;
+
+/// Returns every root of `x^3 + ax + b` in F_p, for an odd prime `p`.
+///
+/// The roots in F_p are the roots of gcd(x^p - x, f), which is then split by
+/// equal-degree factorization with the deterministic shifts x + 0, x + 1, ...
+pub(crate) fn cubic_roots(a: &BigUint, b: &BigUint, p: &BigUint) -> Vec<BigUint> {
+    let f = trim(vec![b % p, a % p, BigUint::zero(), BigUint::one()]);
+    let x_p = pow_mod(&vec![BigUint::zero(), BigUint::one()], p, &f, p);
+    let linear_part = gcd(&f, &sub(&x_p, &vec![BigUint::zero(), BigUint::one()], p), p);
+
+    let mut roots = Vec::new();
+    split_roots(&linear_part, p, &mut roots);
+    roots.sort();
+    roots
+}
+
+/// Collects the roots of a monic polynomial that splits into distinct linear factors
+fn split_roots(f: &Poly, p: &BigUint, roots: &mut Vec<BigUint>) {
+    match f.len() {
+        0 | 1 => return,
+        2 => {
+            roots.push(FiniteField::inv_add(&f[0], p));
+            return;
+        }
+        _ => {}
+    }
+
+    let half = (p - 1u32) / 2u32;
+    let mut shift = BigUint::zero();
+    while &shift < p {
+        let power = pow_mod(&vec![shift.clone(), BigUint::one()], &half, f, p);
+        let factor = gcd(f, &sub(&power, &vec![BigUint::one()], p), p);
+        if factor.len() > 1 && factor.len() < f.len() {
+            split_roots(&factor, p, roots);
+            split_roots(&div_rem(f, &factor, p).0, p, roots);
+            return;
+        }
+        shift += 1u32;
+    }
+}
+
+fn trim(mut f: Poly) -> Poly {
+    while f.last().is_some_and(|c| c.is_zero()) {
+        f.pop();
+    }
+    f
+}
+
+fn sub(f: &Poly, g: &Poly, p: &BigUint) -> Poly {
+    let len = f.len().max(g.len());
+    let zero = BigUint::zero();
+    trim(
+        (0..len)
+            .map(|i| FiniteField::sub(f.get(i).unwrap_or(&zero), g.get(i).unwrap_or(&zero), p))
+            .collect(),
+    )
+}
+
+fn mul(f: &Poly, g: &Poly, p: &BigUint) -> Poly {
+    if f.is_empty() || g.is_empty() {
+        return Vec::new();
+    }
+    let mut product = vec![BigUint::zero(); f.len() + g.len() - 1];
+    for (i, fi) in f.iter().enumerate() {
+        for (j, gj) in g.iter().enumerate() {
+            product[i + j] = FiniteField::add(&product[i + j], &FiniteField::mul(fi, gj, p), p);
+        }
+    }
+    trim(product)
+}
+
+/// Long division, returning the quotient and remainder
+fn div_rem(f: &Poly, g: &Poly, p: &BigUint) -> (Poly, Poly) {
+    let mut remainder = f.clone();
+    if remainder.len() < g.len() {
+        return (Vec::new(), remainder);
+    }
+
+    let lead_inv = FiniteField::inv_mul(&g[g.len() - 1], p);
+    let mut quotient = vec![BigUint::zero(); f.len() - g.len() + 1];
+    while remainder.len() >= g.len() {
+        let shift = remainder.len() - g.len();
+        let coeff = FiniteField::mul(&remainder[remainder.len() - 1], &lead_inv, p);
+        for (i, gi) in g.iter().enumerate() {
+            remainder[shift + i] =
+                FiniteField::sub(&remainder[shift + i], &FiniteField::mul(&coeff, gi, p), p);
+        }
+        quotient[shift] = coeff;
+        remainder = trim(remainder);
+    }
+
+    (trim(quotient), remainder)
+}
+
+/// Returns the monic greatest common divisor
+fn gcd(f: &Poly, g: &Poly, p: &BigUint) -> Poly {
+    let (mut a, mut b) = (f.clone(), g.clone());
+    while !b.is_empty() {
+        let r = div_rem(&a, &b, p).1;
+        a = b;
+        b = r;
+    }
+
+    match a.last() {
+        Some(lead) => {
+            let lead_inv = FiniteField::inv_mul(lead, p);
+            a.iter()
+                .map(|c| FiniteField::mul(c, &lead_inv, p))
+                .collect()
+        }
+        None => a,
+    }
+}
+
+/// Computes `base^exp mod modulus`
+fn pow_mod(base: &Poly, exp: &BigUint, modulus: &Poly, p: &BigUint) -> Poly {
+    let mut result = vec![BigUint::one()];
+    let base = div_rem(base, modulus, p).1;
+
+    for i in (0..exp.bits()).rev() {
+        result = div_rem(&mul(&result, &result, p), modulus, p).1;
+        if exp.bit(i) {
+            result = div_rem(&mul(&result, &base, p), modulus, p).1;
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brute_force_roots(a: u32, b: u32, p: u32) -> Vec<BigUint> {
+        (0..p)
+            .filter(|x| (x * x * x + a * x + b).is_multiple_of(p))
+            .map(BigUint::from)
+            .collect()
+    }
+
+    #[test]
+    fn test_cubic_roots_match_brute_force() {
+        let p = 101u32;
+        for a in 0..p {
+            for b in [0u32, 1, 7, 42, 100] {
+                assert_eq!(
+                    cubic_roots(&BigUint::from(a), &BigUint::from(b), &BigUint::from(p)),
+                    brute_force_roots(a, b, p),
+                    "a = {}, b = {}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+}
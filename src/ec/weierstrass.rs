@@ -1,45 +1,59 @@
-use crate::ec::EllipticCurve;
-use crate::ff::FiniteField;
+use crate::curves::NAMED_CURVES;
+use crate::ec::jacobian::JacobianMul;
+use crate::ec::poly::cubic_roots;
+use crate::ec::{EllipticCurve, Endomorphism, MontgomeryCurve};
+use crate::encoding::{byte_length, to_bytes_be_padded};
+use crate::error::Error;
+use crate::ff::{BigUintField, FiniteField, PrimeField};
 use crate::point::Point;
-use num_bigint::BigUint;
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+use num_bigint::{BigInt, BigUint, RandBigInt, Sign};
+use num_traits::Zero;
+use rand::RngCore;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use sha2::{Digest, Sha256};
+
+/// Window width in bits of the precomputed base point table
+const BASE_TABLE_WINDOW: u64 = 4;
+
+/// Bit length of the random multiple of the order added by `mul_blinded`
+const BLINDING_BITS: u64 = 64;
 
 /// Represents a curve in short Weierstrass form: y^2 = x^3 + ax + b
-pub struct WeierstrassCurve {
+///
+/// The group law computes in the prime field `F`, a `BigUintField` unless the
+/// curve is built with `with_field`. Points and parameters are `BigUint`
+/// whatever the field.
+pub struct WeierstrassCurve<F: PrimeField = BigUintField> {
     a: BigUint,
     b: BigUint,
-    p: BigUint,
+    field: F,
     n: BigUint,
+    h: Option<BigUint>,
     g: Point,
+    base_table: Option<Vec<Vec<Point>>>,
+    endomorphism: Option<Endomorphism>,
 }
 
-impl EllipticCurve for WeierstrassCurve {
+impl<F: PrimeField> EllipticCurve for WeierstrassCurve<F> {
     fn add(&self, p1: &Point, p2: &Point) -> Point {
-        match (p1, p2) {
-            (Point::Identity, _) => p2.clone(),
-            (_, Point::Identity) => p1.clone(),
-            (Point::Coordinates(x1, y1), Point::Coordinates(x2, y2)) => {
-                if x1 == x2 {
-                    let y2_plus_y1 = FiniteField::add(y2, y1, &self.p);
-                    if y2_plus_y1 == BigUint::from(0u32) {
-                        return Point::Identity;
-                    }
-                    if y1 == y2 {
-                        return self.double(p1);
-                    }
-                }
-                self.add_distinct(x1, y1, x2, y2)
-            }
-        }
+        self.add_with_info(p1, p2).0
     }
 
     fn double(&self, p: &Point) -> Point {
         match p {
             Point::Identity => Point::Identity,
             Point::Coordinates(x, y) => {
-                let s = self.calculate_tangent_slope(x, y);
-                let x3 = self.calculate_x3(&s, x, x);
-                let y3 = self.calculate_y3(&s, x, &x3, y);
-                Point::Coordinates(x3, y3)
+                let (x, y) = (self.field.element(x), self.field.element(y));
+                // a vertical tangent: points of order 2
+                if self.field.is_zero(&y) {
+                    return Point::Identity;
+                }
+                let s = self.calculate_tangent_slope(&x, &y);
+                self.third_point(&s, &x, &x, &y)
             }
         }
     }
@@ -63,84 +77,758 @@ impl EllipticCurve for WeierstrassCurve {
     fn is_on_curve(&self, a: &Point) -> bool {
         match a {
             Point::Coordinates(x, y) => {
-                let y2 = y.modpow(&BigUint::from(2u32), &self.p);
-                let x3 = x.modpow(&BigUint::from(3u32), &self.p);
-                let ax = FiniteField::mul(&self.a, x, &self.p);
-                let x3plusax = FiniteField::add(&x3, &ax, &self.p);
-                y2 == FiniteField::add(&x3plusax, &self.b, &self.p)
+                let y2 = self.field.square(&self.field.element(y));
+                y2 == self.rhs(&self.field.element(x))
             }
             Point::Identity => true,
         }
     }
 
+    fn mul_base(&self, scalar: &BigUint) -> Point {
+        let table = match &self.base_table {
+            Some(table) => table,
+            None => return self.mul(&self.g, scalar),
+        };
+
+        let scalar = scalar % &self.n;
+        let mut result = Point::Identity;
+        for (i, window) in table.iter().enumerate() {
+            let digit = (0..BASE_TABLE_WINDOW).fold(0usize, |acc, bit| {
+                let set = scalar.bit(i as u64 * BASE_TABLE_WINDOW + bit);
+                acc | (usize::from(set) << bit)
+            });
+            if digit != 0 {
+                result = self.add(&result, &window[digit - 1]);
+            }
+        }
+
+        result
+    }
+
     fn order(&self) -> &BigUint {
         &self.n
     }
 
+    fn cofactor(&self) -> Option<&BigUint> {
+        self.h.as_ref()
+    }
+
     fn base_point(&self) -> &Point {
         &self.g
     }
 
     fn field_modulus(&self) -> &BigUint {
-        &self.p
+        self.field.modulus()
+    }
+}
+
+/// Which case of the addition law `WeierstrassCurve::add_with_info` took
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AddKind {
+    /// Two points with different x, joined by a chord
+    Distinct,
+    /// Equal points, added with the tangent
+    Doubled,
+    /// Equal x but different y, so the sum is the identity
+    Inverse,
+    /// At least one operand was the identity
+    Identity,
+}
+
+/// Curves are equal when their parameters are: coefficients, field prime,
+/// order, cofactor and base point. Precomputed tables and the endomorphism
+/// descriptor are derived data and not compared.
+impl<F: PrimeField> PartialEq for WeierstrassCurve<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.a == other.a
+            && self.b == other.b
+            && self.p() == other.p()
+            && self.n == other.n
+            && self.h == other.h
+            && self.g == other.g
+    }
+}
+
+impl<F: PrimeField> WeierstrassCurve<F> {
+    /// Creates a curve over the prime `field.modulus()` whose group law
+    /// computes in `field`; `new` does the same with a `BigUintField`
+    pub fn with_field(field: F, a: BigUint, b: BigUint, n: BigUint, g: Point) -> Self {
+        WeierstrassCurve {
+            a,
+            b,
+            field,
+            n,
+            h: None,
+            g,
+            base_table: None,
+            endomorphism: None,
+        }
+    }
+
+    /// Sets the cofactor, which is unknown otherwise
+    pub fn with_cofactor(mut self, h: BigUint) -> Self {
+        self.h = Some(h);
+        self
+    }
+
+    /// Returns the field the group law computes in
+    pub fn field(&self) -> &F {
+        &self.field
+    }
+
+    /// Returns the coefficient `a` of x in y^2 = x^3 + ax + b
+    pub fn a(&self) -> &BigUint {
+        &self.a
+    }
+
+    /// Returns the constant term `b` of y^2 = x^3 + ax + b
+    pub fn b(&self) -> &BigUint {
+        &self.b
+    }
+
+    /// Builds a point from coordinates reduced modulo `p`, so that equal points
+    /// compare and hash equally
+    pub fn point(&self, x: BigUint, y: BigUint) -> Point {
+        let reduce = |v: &BigUint| self.field.to_biguint(&self.field.element(v));
+        Point::Coordinates(reduce(&x), reduce(&y))
+    }
+
+    /// Adds two points like `add` and also reports which case of the
+    /// addition law produced the sum
+    pub fn add_with_info(&self, p1: &Point, p2: &Point) -> (Point, AddKind) {
+        match (p1, p2) {
+            (Point::Identity, _) => (p2.clone(), AddKind::Identity),
+            (_, Point::Identity) => (p1.clone(), AddKind::Identity),
+            (Point::Coordinates(x1, y1), Point::Coordinates(x2, y2)) => {
+                let (x1, y1) = (self.field.element(x1), self.field.element(y1));
+                let (x2, y2) = (self.field.element(x2), self.field.element(y2));
+                // with equal x the chord is vertical unless the points coincide.
+                // For points on the curve that means y2 = -y1, but malformed
+                // input can have any other y2; both give the identity rather
+                // than a division by x2 - x1 = 0
+                if x1 == x2 {
+                    if y1 == y2 {
+                        return (self.double(p1), AddKind::Doubled);
+                    }
+                    return (Point::Identity, AddKind::Inverse);
+                }
+                (self.add_distinct(&x1, &y1, &x2, &y2), AddKind::Distinct)
+            }
+        }
+    }
+
+    /// Returns the inverse of a point, (x, -y)
+    pub fn negate(&self, p: &Point) -> Point {
+        match p {
+            Point::Identity => Point::Identity,
+            Point::Coordinates(x, y) => {
+                let y = self.field.neg(&self.field.element(y));
+                Point::Coordinates(x.clone(), self.field.to_biguint(&y))
+            }
+        }
+    }
+
+    fn p(&self) -> &BigUint {
+        self.field.modulus()
+    }
+
+    fn curve_rhs(&self, x: &BigUint) -> BigUint {
+        self.field.to_biguint(&self.rhs(&self.field.element(x)))
+    }
+
+    fn rhs(&self, x: &F::Element) -> F::Element {
+        let x3 = self.field.mul(&self.field.square(x), x);
+        let b = self.field.element(&self.b);
+        // a = 0 on secp256k1 and the other Koblitz curves: skip the ax term
+        if self.a.is_zero() {
+            return self.field.add(&x3, &b);
+        }
+        let ax = self.field.mul(&self.field.element(&self.a), x);
+        self.field.add(&self.field.add(&x3, &ax), &b)
+    }
+
+    fn add_distinct(
+        &self,
+        x1: &F::Element,
+        y1: &F::Element,
+        x2: &F::Element,
+        y2: &F::Element,
+    ) -> Point {
+        let s = self.calculate_slope(x1, y1, x2, y2);
+        self.third_point(&s, x1, x2, y1)
+    }
+
+    /// Reflects the third intersection of the line with slope `s` through
+    /// `(x1, y1)` and a point with x coordinate `x2`
+    fn third_point(
+        &self,
+        s: &F::Element,
+        x1: &F::Element,
+        x2: &F::Element,
+        y1: &F::Element,
+    ) -> Point {
+        let x3 = self.calculate_x3(s, x1, x2);
+        let y3 = self.calculate_y3(s, x1, &x3, y1);
+        Point::Coordinates(self.field.to_biguint(&x3), self.field.to_biguint(&y3))
+    }
+
+    fn calculate_slope(
+        &self,
+        x1: &F::Element,
+        y1: &F::Element,
+        x2: &F::Element,
+        y2: &F::Element,
+    ) -> F::Element {
+        let numerator = self.field.sub(y2, y1);
+        let denominator = self.field.sub(x2, x1);
+        self.field.mul(&numerator, &self.field.inv(&denominator))
+    }
+
+    fn calculate_x3(&self, s: &F::Element, x1: &F::Element, x2: &F::Element) -> F::Element {
+        self.field
+            .sub(&self.field.sub(&self.field.square(s), x1), x2)
+    }
+
+    fn calculate_y3(
+        &self,
+        s: &F::Element,
+        x1: &F::Element,
+        x3: &F::Element,
+        y1: &F::Element,
+    ) -> F::Element {
+        self.field
+            .sub(&self.field.mul(s, &self.field.sub(x1, x3)), y1)
+    }
+
+    fn calculate_tangent_slope(&self, x: &F::Element, y: &F::Element) -> F::Element {
+        let x2 = self.field.square(x);
+        let three_x2 = self.field.add(&self.field.add(&x2, &x2), &x2);
+        let numerator = if self.a.is_zero() {
+            three_x2
+        } else {
+            self.field.add(&three_x2, &self.field.element(&self.a))
+        };
+        let denominator = self.field.add(y, y);
+        self.field.mul(&numerator, &self.field.inv(&denominator))
     }
 }
 
 impl WeierstrassCurve {
     pub fn new(a: BigUint, b: BigUint, p: BigUint, n: BigUint, g: Point) -> Self {
-        WeierstrassCurve { a, b, p, n, g }
+        Self::with_field(BigUintField::new(p), a, b, n, g)
     }
 
-    fn add_distinct(&self, x1: &BigUint, y1: &BigUint, x2: &BigUint, y2: &BigUint) -> Point {
-        let s = self.calculate_slope(x1, y1, x2, y2);
-        let x3 = self.calculate_x3(&s, x1, x2);
-        let y3 = self.calculate_y3(&s, x1, &x3, y1);
-        Point::Coordinates(x3, y3)
-    }
-
-    fn calculate_slope(&self, x1: &BigUint, y1: &BigUint, x2: &BigUint, y2: &BigUint) -> BigUint {
-        let numerator = FiniteField::sub(y2, y1, &self.p);
-        let denominator = FiniteField::sub(x2, x1, &self.p);
-        FiniteField::mul(
-            &numerator,
-            &FiniteField::inv_mul(&denominator, &self.p),
-            &self.p,
-        )
-    }
-
-    fn calculate_x3(&self, s: &BigUint, x1: &BigUint, x2: &BigUint) -> BigUint {
-        FiniteField::sub(
-            &FiniteField::sub(&s.modpow(&BigUint::from(2u32), &self.p), x1, &self.p),
-            x2,
-            &self.p,
-        )
-    }
-
-    fn calculate_y3(&self, s: &BigUint, x1: &BigUint, x3: &BigUint, y1: &BigUint) -> BigUint {
-        FiniteField::sub(
-            &FiniteField::mul(s, &FiniteField::sub(x1, x3, &self.p), &self.p),
-            y1,
-            &self.p,
-        )
-    }
-
-    fn calculate_tangent_slope(&self, x: &BigUint, y: &BigUint) -> BigUint {
-        let numerator = FiniteField::add(
-            &FiniteField::mul(
-                &BigUint::from(3u32),
-                &x.modpow(&BigUint::from(2u32), &self.p),
-                &self.p,
-            ),
-            &self.a,
-            &self.p,
+    /// Attaches an endomorphism (x, y) ↦ (βx, y) that equals multiplication by
+    /// `lambda` on the prime-order subgroup, enabling `mul_glv`. `b1` and `b2`
+    /// are a reduced basis of the lattice {(a, b) : a + b·lambda ≡ 0 (mod n)}.
+    pub fn with_endomorphism(
+        mut self,
+        beta: BigUint,
+        lambda: BigUint,
+        b1: (BigInt, BigInt),
+        b2: (BigInt, BigInt),
+    ) -> Self {
+        self.endomorphism = Some(Endomorphism {
+            beta,
+            lambda,
+            b1,
+            b2,
+        });
+        self
+    }
+
+    /// Returns the endomorphism set by `with_endomorphism`, if any
+    pub fn endomorphism(&self) -> Option<&Endomorphism> {
+        self.endomorphism.as_ref()
+    }
+
+    /// Like `new`, but checks the parameters: the curve must be non-singular,
+    /// and the base point must lie on it and satisfy `n·G == Identity`.
+    pub fn new_checked(
+        a: BigUint,
+        b: BigUint,
+        p: BigUint,
+        n: BigUint,
+        g: Point,
+    ) -> Result<Self, Error> {
+        let curve = Self::new(a, b, p, n, g);
+        if !curve.is_nonsingular() {
+            return Err(Error::SingularCurve);
+        }
+        if curve.g == Point::Identity || !curve.is_on_curve(&curve.g) {
+            return Err(Error::NotOnCurve);
+        }
+        // n·G != O: G does not generate a subgroup of order n
+        if curve.mul(&curve.g, &curve.n) != Point::Identity {
+            return Err(Error::NotInSubgroup);
+        }
+        Ok(curve)
+    }
+
+    /// Checks the discriminant: the curve is singular, and the group law breaks
+    /// down, when `4a^3 + 27b^2 ≡ 0 (mod p)`
+    pub fn is_nonsingular(&self) -> bool {
+        let p = self.p();
+        let four_a3 = FiniteField::mul(
+            &BigUint::from(4u32),
+            &self.a.modpow(&BigUint::from(3u32), p),
+            p,
         );
-        let denominator = FiniteField::mul(&BigUint::from(2u32), y, &self.p);
-        FiniteField::mul(
-            &numerator,
-            &FiniteField::inv_mul(&denominator, &self.p),
-            &self.p,
-        )
+        let twenty_seven_b2 = FiniteField::mul(
+            &BigUint::from(27u32),
+            &FiniteField::mul(&self.b, &self.b, p),
+            p,
+        );
+        !FiniteField::add(&four_a3, &twenty_seven_b2, p).is_zero()
+    }
+
+    /// Generates a random curve over the prime `p > 3` for experiments.
+    ///
+    /// Draws `a` and `b` until the curve is non-singular and its number of
+    /// points is a prime `n` times a cofactor of at most 4 and below `n`, so
+    /// the subgroup of order `n` is unique, then takes the base point from a
+    /// random point with the cofactor cleared. Points are counted
+    /// naively with one Jacobi symbol per x, so this is only practical for
+    /// small primes.
+    pub fn generate<R: RngCore>(p: &BigUint, rng: &mut R) -> WeierstrassCurve {
+        assert!(p > &BigUint::from(3u32), "p must be a prime greater than 3");
+
+        loop {
+            let a = rng.gen_biguint_below(p);
+            let b = rng.gen_biguint_below(p);
+            let curve = Self::new(a, b, p.clone(), BigUint::from(1u32), Point::Identity);
+            if !curve.is_nonsingular() {
+                continue;
+            }
+
+            let points = curve.count_points();
+            let n = largest_prime_factor(&points);
+            let h = &points / &n;
+            // with n <= h the n-torsion can be Z_n x Z_n, where no point has
+            // order n·h and clearing the cofactor may always give the identity
+            if h > BigUint::from(4u32) || n <= h {
+                continue;
+            }
+
+            let g = loop {
+                let g = curve.mul(&curve.random_point(rng), &h);
+                if g != Point::Identity {
+                    break g;
+                }
+            };
+            return Self::new(curve.a, curve.b, p.clone(), n, g).with_cofactor(h);
+        }
+    }
+
+    /// Solves `target = x·base` for `x <= max` with baby-step giant-step, or
+    /// returns `None` if no such `x` exists.
+    ///
+    /// Stores `m = ⌈√(max + 1)⌉` baby steps `j·base` and then walks
+    /// `target - i·m·base`, so both time and memory are `O(√max)`. Fine for toy
+    /// curves; hopeless at cryptographic sizes, which is the point.
+    pub fn discrete_log(&self, base: &Point, target: &Point, max: &BigUint) -> Option<BigUint> {
+        let m = max.sqrt() + 1u32;
+        let key = |point: &Point| point.coordinates().map(|(x, y)| (x.clone(), y.clone()));
+
+        let mut baby_steps = BTreeMap::new();
+        let mut step = Point::Identity;
+        let mut j = BigUint::zero();
+        while j < m {
+            baby_steps.entry(key(&step)).or_insert_with(|| j.clone());
+            step = self.add(&step, base);
+            j += 1u32;
+        }
+
+        // `step` is now m·base
+        let giant_step = self.negate(&step);
+        let mut gamma = target.clone();
+        let mut i = BigUint::zero();
+        while i < m {
+            if let Some(j) = baby_steps.get(&key(&gamma)) {
+                let x = &i * &m + j;
+                return (&x <= max).then_some(x);
+            }
+            gamma = self.add(&gamma, &giant_step);
+            i += 1u32;
+        }
+        None
+    }
+
+    /// Counts the points on the curve, including the identity, as
+    /// `p + 1 + Σ (x^3 + ax + b | p)` over all x in F_p
+    fn count_points(&self) -> BigUint {
+        let mut x = BigUint::zero();
+        let mut count = self.p() + 1u32;
+        while &x < self.p() {
+            match FiniteField::jacobi_symbol(&self.curve_rhs(&x), self.p()) {
+                1 => count += 1u32,
+                -1 => count -= 1u32,
+                _ => {}
+            }
+            x += 1u32;
+        }
+        count
+    }
+
+    /// Returns the number of points on the quadratic twist, or `None` if the
+    /// cofactor is unknown. A curve and its twist together have `2p + 2`
+    /// points, so this is `2p + 2 - n·h`.
+    ///
+    /// An x-only ECDH implementation that doesn't check its input can be fed
+    /// points on the twist, so the twist order matters as much as the curve's.
+    pub fn twist_order(&self) -> Option<BigUint> {
+        let h = self.h.as_ref()?;
+        Some((self.p() << 1u32) + 2u32 - &self.n * h)
+    }
+
+    /// Checks twist security given the prime factorization of `twist_order()`,
+    /// one entry per prime factor with repetition: the factors must multiply
+    /// to the twist order and the largest must have at least `min_bits` bits,
+    /// so that a discrete log on the twist stays out of reach. Returns false
+    /// for a factorization of some other number, or if the cofactor is
+    /// unknown.
+    pub fn is_twist_secure(&self, factors: &[BigUint], min_bits: u64) -> bool {
+        let product = factors
+            .iter()
+            .fold(BigUint::from(1u32), |product, factor| product * factor);
+        self.twist_order() == Some(product)
+            && factors
+                .iter()
+                .max()
+                .is_some_and(|largest| largest.bits() >= min_bits)
+    }
+
+    /// Returns the canonical name of a curve from `lookup_by_name`'s registry,
+    /// or `None` for other curves
+    pub fn name(&self) -> Option<&'static str> {
+        NAMED_CURVES
+            .iter()
+            .find(|(_, create)| create() == *self)
+            .map(|(name, _)| *name)
+    }
+
+    /// Full public key validation (NIST SP 800-56A, section 5.6.2.3.3): the point
+    /// is not the identity, its coordinates lie in `[0, p)`, it is on the curve
+    /// and it is in the prime-order subgroup.
+    pub fn validate_public_key_full(&self, point: &Point) -> Result<(), Error> {
+        let (x, y) = match point {
+            Point::Identity => return Err(Error::Identity),
+            Point::Coordinates(x, y) => (x, y),
+        };
+        if x >= self.p() || y >= self.p() {
+            return Err(Error::InvalidEncoding);
+        }
+        if !self.is_on_curve(point) {
+            return Err(Error::NotOnCurve);
+        }
+        if self.mul(point, &self.n) != Point::Identity {
+            return Err(Error::NotInSubgroup);
+        }
+        Ok(())
+    }
+
+    /// Returns the order of `point` if it is at most `max`.
+    ///
+    /// Without a factorization of the group order `n·h`, this tries each of its
+    /// divisors `d <= max` in increasing order and returns the first with
+    /// `d·P = O`; with an unknown cofactor it tries every `d <= max`. The
+    /// search is linear in `max`, so it suits detecting points in small
+    /// subgroups; use `point_order_with_factors` for the exact order of
+    /// arbitrary points. Returns `None` for points off the curve.
+    pub fn point_order(&self, point: &Point, max: &BigUint) -> Option<BigUint> {
+        if !self.is_on_curve(point) {
+            return None;
+        }
+
+        let group_order = self.h.as_ref().map(|h| &self.n * h);
+        let mut d = BigUint::from(1u32);
+        while &d <= max && group_order.as_ref().is_none_or(|order| &d <= order) {
+            let divides = group_order
+                .as_ref()
+                .is_none_or(|order| (order % &d).is_zero());
+            if divides && self.mul(point, &d) == Point::Identity {
+                return Some(d);
+            }
+            d += 1u32;
+        }
+        None
     }
+
+    /// Returns the exact order of `point` given a multiple of it, typically the
+    /// group order, as `(prime, exponent)` pairs.
+    ///
+    /// Starting from the full product, each prime is divided out while the
+    /// point is still annihilated. Returns `None` if the product does not
+    /// annihilate the point or the point is off the curve.
+    pub fn point_order_with_factors(
+        &self,
+        point: &Point,
+        factors: &[(BigUint, u32)],
+    ) -> Option<BigUint> {
+        if !self.is_on_curve(point) {
+            return None;
+        }
+
+        let mut order = factors
+            .iter()
+            .fold(BigUint::from(1u32), |acc, (q, e)| acc * q.pow(*e));
+        if self.mul(point, &order) != Point::Identity {
+            return None;
+        }
+
+        for (q, e) in factors {
+            for _ in 0..*e {
+                let reduced = &order / q;
+                if self.mul(point, &reduced) != Point::Identity {
+                    break;
+                }
+                order = reduced;
+            }
+        }
+        Some(order)
+    }
+
+    /// Precomputes multiples of the base point for `mul_base`.
+    ///
+    /// The scalar is split into 4-bit windows; for window `i` the table holds
+    /// `j·2^(4i)·G` for every nonzero digit `j`, so `mul_base` needs one
+    /// addition per window and no doublings.
+    pub fn with_base_point_table(mut self) -> Self {
+        let windows = self.n.bits().div_ceil(BASE_TABLE_WINDOW);
+        let mut table = Vec::with_capacity(windows as usize);
+        let mut window_base = self.g.clone();
+
+        for _ in 0..windows {
+            let mut row = Vec::with_capacity((1 << BASE_TABLE_WINDOW) - 1);
+            let mut multiple = window_base.clone();
+            for _ in 1..(1 << BASE_TABLE_WINDOW) {
+                row.push(multiple.clone());
+                multiple = self.add(&multiple, &window_base);
+            }
+            // after the inner loop `multiple` is 2^4 times the window base
+            window_base = multiple;
+            table.push(row);
+        }
+
+        self.base_table = Some(table);
+        self
+    }
+
+    /// Multiplies one point by many scalars, returning the products in order.
+    ///
+    /// With the `rayon` feature the scalars are processed in parallel;
+    /// otherwise this is `scalars.iter().map(|s| self.mul(p, s))`.
+    pub fn mul_many(&self, p: &Point, scalars: &[BigUint]) -> Vec<Point> {
+        #[cfg(feature = "rayon")]
+        let products = scalars.par_iter().map(|s| self.mul(p, s)).collect();
+        #[cfg(not(feature = "rayon"))]
+        let products = scalars.iter().map(|s| self.mul(p, s)).collect();
+        products
+    }
+
+    /// Computes the sum of `scalar·point` over `terms` with one shared chain
+    /// of doublings (Straus's method without precomputed tables), so the cost
+    /// is one multiplication's doublings plus an addition per set bit.
+    pub fn mul_sum(&self, terms: &[(Point, BigUint)]) -> Point {
+        let bits = terms.iter().map(|(_, s)| s.bits()).max().unwrap_or(0);
+        let mut result = Point::Identity;
+        for i in (0..bits).rev() {
+            result = self.double(&result);
+            for (point, scalar) in terms {
+                if scalar.bit(i) {
+                    result = self.add(&result, point);
+                }
+            }
+        }
+        result
+    }
+
+    /// Scalar multiplication with a randomly blinded scalar.
+    ///
+    /// Computes `(scalar + r·n)·P` for a fresh 64-bit `r`, which equals
+    /// `scalar·P` for `P` in the prime-order subgroup, but runs a different
+    /// sequence of doublings and additions on every call, so power traces of
+    /// repeated multiplications by the same secret do not line up.
+    pub fn mul_blinded<R: RngCore>(&self, p: &Point, scalar: &BigUint, rng: &mut R) -> Point {
+        let blind = rng.gen_biguint(BLINDING_BITS);
+        self.mul(p, &(scalar + blind * &self.n))
+    }
+
+    /// Scalar multiplication with the GLV method.
+    ///
+    /// The scalar is reduced modulo the order and split as `k1 + k2·λ` with
+    /// both halves about half as long, then `k1·P + k2·φ(P)` is computed with
+    /// Shamir's trick, sharing one run of half-length doublings. Only valid
+    /// for points in the prime-order subgroup. Curves without an endomorphism
+    /// (see `with_endomorphism`) fall back to `mul`.
+    pub fn mul_glv(&self, p: &Point, scalar: &BigUint) -> Point {
+        let endomorphism = match self.endomorphism() {
+            Some(endomorphism) => endomorphism,
+            None => return self.mul(p, scalar),
+        };
+
+        let (k1, k2) = endomorphism.decompose(&self.reduce_scalar(scalar), &self.n);
+        let p1 = match k1.sign() {
+            Sign::Minus => self.negate(p),
+            _ => p.clone(),
+        };
+        let phi = endomorphism.apply(p, self.p());
+        let p2 = match k2.sign() {
+            Sign::Minus => self.negate(&phi),
+            _ => phi,
+        };
+
+        self.mul_double(&p1, k1.magnitude(), &p2, k2.magnitude())
+    }
+
+    /// Scalar multiplication with the field arithmetic done in Montgomery
+    /// form.
+    ///
+    /// Computes the same point as `mul`, but in Jacobian coordinates where
+    /// each field multiplication is finished by Montgomery reduction instead
+    /// of a division by `p`, and the only inversion converts the result back.
+    /// The coordinates enter and leave Montgomery form once per call.
+    pub fn mul_redc(&self, p: &Point, scalar: &BigUint) -> Point {
+        JacobianMul::new(&self.a, self.p()).mul(p, scalar)
+    }
+
+    /// Finds a birationally equivalent Montgomery curve By^2 = x^3 + Ax^2 + x.
+    ///
+    /// This needs a root α of x^3 + ax + b in F_p (a point of order two) for
+    /// which 3α^2 + a is a square. With s = 1/sqrt(3α^2 + a) the Montgomery
+    /// curve has A = 3αs and B = s, and the returned mapping sends (x, y) to
+    /// (s(x - α), sy). `MontgomeryCurve::to_weierstrass` maps back. Returns
+    /// `None` when no such root exists, e.g. for prime-order curves, and for
+    /// singular curves, where 3α^2 + a vanishes at the repeated root.
+    pub fn to_montgomery(&self) -> Option<(MontgomeryCurve, impl Fn(&Point) -> Point)> {
+        if !self.is_nonsingular() {
+            return None;
+        }
+        let p = self.p().clone();
+        let (alpha, s) = cubic_roots(&self.a, &self.b, &p)
+            .into_iter()
+            .find_map(|alpha| {
+                let alpha2 = FiniteField::mul(&alpha, &alpha, &p);
+                let slope = FiniteField::add(
+                    &FiniteField::mul(&BigUint::from(3u32), &alpha2, &p),
+                    &self.a,
+                    &p,
+                );
+                let root = FiniteField::sqrt(&slope, &p)?;
+                Some((alpha, FiniteField::inv_mul(&root, &p)))
+            })?;
+
+        let a = FiniteField::mul(&FiniteField::mul(&BigUint::from(3u32), &alpha, &p), &s, &p);
+        let b = s.clone();
+        let map = move |point: &Point| match point {
+            Point::Identity => Point::Identity,
+            Point::Coordinates(x, y) => Point::Coordinates(
+                FiniteField::mul(&s, &FiniteField::sub(x, &alpha, &p), &p),
+                FiniteField::mul(&s, y, &p),
+            ),
+        };
+
+        let mut curve = MontgomeryCurve::new(a, b, self.p().clone(), self.n.clone(), map(&self.g));
+        if let Some(h) = &self.h {
+            curve = curve.with_cofactor(h.clone());
+        }
+        Some((curve, map))
+    }
+
+    /// Maps arbitrary bytes to a curve point using try-and-increment.
+    ///
+    /// Each attempt hashes `data || counter` with SHA-256 to a candidate x and
+    /// accepts it once `x^3 + ax + b` has a square root. The number of attempts
+    /// depends on the input, so this is not constant time.
+    pub fn hash_to_curve(&self, data: &[u8]) -> Point {
+        let mut counter = 0u32;
+        loop {
+            let digest = Sha256::new()
+                .chain_update(data)
+                .chain_update(counter.to_be_bytes())
+                .finalize();
+            let x = BigUint::from_bytes_be(&digest) % self.p();
+            if let Some(y) = FiniteField::sqrt(&self.curve_rhs(&x), self.p()) {
+                return Point::Coordinates(x, y);
+            }
+            counter += 1;
+        }
+    }
+
+    /// Returns a random affine point: draws x uniformly below `p` until
+    /// `x^3 + ax + b` has a square root, then picks one of the two roots at random
+    pub fn random_point<R: RngCore>(&self, rng: &mut R) -> Point {
+        loop {
+            let x = rng.gen_biguint_below(self.p());
+            if let Some(y) = FiniteField::sqrt(&self.curve_rhs(&x), self.p()) {
+                let y = if rng.next_u32() & 1 == 1 && !y.is_zero() {
+                    self.p() - y
+                } else {
+                    y
+                };
+                return Point::Coordinates(x, y);
+            }
+        }
+    }
+
+    /// Encodes a point in SEC1 compressed form: `0x02`/`0x03` (y parity) followed
+    /// by the x coordinate. The identity encodes as the single byte `0x00`.
+    pub fn to_sec1_compressed(&self, point: &Point) -> Vec<u8> {
+        match point {
+            Point::Identity => vec![0x00],
+            Point::Coordinates(x, y) => {
+                let mut bytes = vec![if y.bit(0) { 0x03 } else { 0x02 }];
+                bytes.extend(to_bytes_be_padded(x, byte_length(self.p())));
+                bytes
+            }
+        }
+    }
+
+    /// Decodes a SEC1 compressed point, recovering y from the curve equation
+    pub fn from_sec1_compressed(&self, bytes: &[u8]) -> Result<Point, Error> {
+        if bytes.len() != 1 + byte_length(self.p()) || !matches!(bytes[0], 0x02 | 0x03) {
+            return Err(Error::InvalidEncoding);
+        }
+
+        let x = BigUint::from_bytes_be(&bytes[1..]);
+        if &x >= self.p() {
+            return Err(Error::InvalidEncoding);
+        }
+
+        let rhs = self.curve_rhs(&x);
+        if !FiniteField::is_quadratic_residue(&rhs, self.p()) {
+            return Err(Error::NotOnCurve);
+        }
+        let y = FiniteField::sqrt(&rhs, self.p()).ok_or(Error::NotOnCurve)?;
+        let odd = bytes[0] == 0x03;
+        let y = if y.bit(0) == odd {
+            y
+        } else if y == BigUint::from(0u32) {
+            return Err(Error::InvalidEncoding);
+        } else {
+            self.p() - y
+        };
+
+        Ok(Point::Coordinates(x, y))
+    }
+}
+
+/// Largest prime factor of `m > 1`, by trial division
+fn largest_prime_factor(m: &BigUint) -> BigUint {
+    let mut m = m.clone();
+    let mut largest = BigUint::from(1u32);
+    let mut d = BigUint::from(2u32);
+    while &d * &d <= m {
+        while (&m % &d).is_zero() {
+            m /= &d;
+            largest = d.clone();
+        }
+        d += 1u32;
+    }
+    if m > BigUint::from(1u32) {
+        largest = m;
+    }
+    largest
 }
 
 #[cfg(test)]
@@ -151,9 +839,12 @@ mod tests {
         WeierstrassCurve {
             a: BigUint::from(2u32),
             b: BigUint::from(2u32),
-            p: BigUint::from(17u32),
+            field: BigUintField::new(BigUint::from(17u32)),
             n: BigUint::from(18u32),
+            h: None,
             g: Point::Coordinates(BigUint::from(5u32), BigUint::from(1u32)),
+            base_table: None,
+            endomorphism: None,
         }
     }
 
@@ -192,6 +883,78 @@ mod tests {
             assert_eq!(curve.add(&p1, &p2), Point::Identity);
         }
 
+        #[test]
+        fn test_add_same_x_unrelated_y() {
+            // (5, 1) is on the curve, (5, 7) is not and 7 != 17 - 1
+            let curve = create_test_curve();
+            let p1 = Point::Coordinates(BigUint::from(5u32), BigUint::from(1u32));
+            let p2 = Point::Coordinates(BigUint::from(5u32), BigUint::from(7u32));
+
+            assert_eq!(curve.add(&p1, &p2), Point::Identity);
+            assert_eq!(curve.add(&p2, &p1), Point::Identity);
+
+            // an unreduced x that matches modulo p is still the same x
+            let unreduced = Point::Coordinates(BigUint::from(22u32), BigUint::from(16u32));
+            assert_eq!(curve.add(&p1, &unreduced), Point::Identity);
+        }
+
+        #[test]
+        fn test_double_point_of_order_two() {
+            // (0, 0) lies on y^2 = x^3 + x and has order 2
+            let curve = WeierstrassCurve::new(
+                BigUint::from(1u32),
+                BigUint::from(0u32),
+                BigUint::from(23u32),
+                BigUint::from(2u32),
+                Point::Coordinates(BigUint::from(0u32), BigUint::from(0u32)),
+            );
+            let p = curve.base_point();
+
+            assert_eq!(curve.double(p), Point::Identity);
+            assert_eq!(curve.add(p, p), Point::Identity);
+            assert_eq!(curve.mul(p, &BigUint::from(3u32)), *p);
+        }
+
+        #[test]
+        fn test_add_with_info_reports_branch() {
+            let curve = create_test_curve();
+            let g = curve.base_point();
+            let g2 = curve.double(g);
+
+            assert_eq!(
+                curve.add_with_info(g, &g2),
+                (curve.add(g, &g2), AddKind::Distinct)
+            );
+            assert_eq!(curve.add_with_info(g, g), (g2.clone(), AddKind::Doubled));
+            assert_eq!(
+                curve.add_with_info(g, &curve.negate(g)),
+                (Point::Identity, AddKind::Inverse)
+            );
+            assert_eq!(
+                curve.add_with_info(&Point::Identity, g),
+                (g.clone(), AddKind::Identity)
+            );
+            assert_eq!(
+                curve.add_with_info(&g2, &Point::Identity),
+                (g2, AddKind::Identity)
+            );
+        }
+
+        #[test]
+        fn test_add_detect_identity() {
+            let curve = create_test_curve();
+            let p = Point::Coordinates(BigUint::from(5u32), BigUint::from(1u32));
+            let neg_p = Point::Coordinates(BigUint::from(5u32), BigUint::from(16u32));
+
+            let (sum, is_identity) = curve.add_detect_identity(&p, &neg_p);
+            assert_eq!(sum, Point::Identity);
+            assert!(is_identity);
+
+            let (sum, is_identity) = curve.add_detect_identity(&p, &p);
+            assert_eq!(sum, curve.double(&p));
+            assert!(!is_identity);
+        }
+
         #[test]
         fn test_double() {
             let curve = create_test_curve();
@@ -215,20 +978,1136 @@ mod tests {
             assert!(matches!(result, Point::Coordinates(_, _)));
             assert!(curve.is_on_curve(&result));
         }
-    }
 
-    mod curve_properties {
-        use super::*;
+        #[test]
+        fn test_mul_double_matches_naive() {
+            use num_bigint::RandBigInt;
+
+            let mut rng = rand::thread_rng();
+            let toy = create_test_curve();
+            let secp256k1 = crate::curves::secp256k1::create_secp256k1_weierstrass();
+
+            for curve in [&toy, &secp256k1] {
+                let g = curve.base_point();
+                let q = curve.mul(g, &BigUint::from(7u32));
+                for _ in 0..5 {
+                    let s1 = rng.gen_biguint_below(curve.order());
+                    let s2 = rng.gen_biguint_below(curve.order());
+                    let naive = curve.add(&curve.mul(g, &s1), &curve.mul(&q, &s2));
+
+                    assert_eq!(curve.mul_double(g, &s1, &q, &s2), naive);
+                }
+            }
+        }
 
         #[test]
-        fn test_is_on_curve() {
-            let curve = create_test_curve();
-            let p = Point::Coordinates(BigUint::from(3u32), BigUint::from(1u32));
-            assert!(curve.is_on_curve(&p));
+        fn test_mul_redc_matches_mul() {
+            use num_bigint::RandBigInt;
+            use rand::rngs::StdRng;
+            use rand::SeedableRng;
 
-            let not_on_curve = Point::Coordinates(BigUint::from(4u32), BigUint::from(2u32));
-            assert!(!curve.is_on_curve(&not_on_curve));
+            let toy = create_test_curve();
+            let g = toy.base_point();
+            for k in 0u32..45 {
+                let k = BigUint::from(k);
+                assert_eq!(toy.mul_redc(g, &k), toy.mul(g, &k));
+            }
+            assert_eq!(
+                toy.mul_redc(&Point::Identity, &BigUint::from(3u32)),
+                Point::Identity
+            );
+
+            let mut rng = StdRng::seed_from_u64(92);
+            let secp256k1 = crate::curves::secp256k1::create_secp256k1_weierstrass();
+            let p256 = crate::curves::secp256r1::create_secp256r1_weierstrass();
+            for curve in [&secp256k1, &p256] {
+                let g = curve.base_point();
+                assert_eq!(curve.mul_redc(g, curve.order()), Point::Identity);
+                for _ in 0..3 {
+                    let k = rng.gen_biguint(300);
+                    let q = curve.mul(g, &rng.gen_biguint_below(curve.order()));
+                    assert_eq!(curve.mul_redc(g, &k), curve.mul(g, &k));
+                    assert_eq!(curve.mul_redc(&q, &k), curve.mul(&q, &k));
+                }
+            }
         }
-    }
-}
 
+        #[test]
+        fn test_mul_double_through_identity() {
+            // G = (5, 1) has order 19 on the test curve
+            let curve = create_test_curve();
+            let g = curve.base_point();
+            let neg_g = curve.negate(g);
+            let s = |k: u32| BigUint::from(k);
+
+            // p1 + p2 is the identity, so every (1, 1) bit pair adds nothing;
+            // 5·G - 4·G passes through the identity after the top bit
+            assert_eq!(curve.mul_double(g, &s(5), &neg_g, &s(4)), *g);
+            assert_eq!(curve.mul_double(g, &s(6), &neg_g, &s(6)), Point::Identity);
+
+            // 19 = 0b10011 reaches the identity by adding G to 18·G; the
+            // remaining bits of 39 = 0b100111 double it and add G again
+            assert_eq!(curve.mul_double(g, &s(19), g, &s(0)), Point::Identity);
+            assert_eq!(curve.mul_double(g, &s(39), g, &s(0)), *g);
+            assert_eq!(curve.mul_double(g, &s(0), g, &s(39)), *g);
+
+            // the prefixes of 9 = 0b1001 and 10 = 0b1010 sum to 19 after all
+            // four bits, and to 38 with the extra bit of 18 and 20
+            let q = curve.mul(g, &s(3));
+            for (s1, s2) in [(9, 10), (18, 20), (19, 19), (37, 2)] {
+                let naive = curve.add(&curve.mul(g, &s(s1)), &curve.mul(g, &s(s2)));
+                assert_eq!(curve.mul_double(g, &s(s1), g, &s(s2)), naive);
+                let naive = curve.add(&curve.mul(g, &s(s1)), &curve.mul(&q, &s(s2)));
+                assert_eq!(curve.mul_double(g, &s(s1), &q, &s(s2)), naive);
+            }
+
+            // the identity as an input point
+            assert_eq!(
+                curve.mul_double(&Point::Identity, &s(7), g, &s(2)),
+                curve.mul(g, &s(2))
+            );
+            assert_eq!(curve.mul_double(g, &s(0), g, &s(0)), Point::Identity);
+
+            let secp256k1 = crate::curves::secp256k1::create_secp256k1_weierstrass();
+            let g = secp256k1.base_point();
+            let k = BigUint::parse_bytes(b"C0FFEE0123456789ABCDEF", 16).unwrap();
+            assert_eq!(
+                secp256k1.mul_double(g, &k, &secp256k1.negate(g), &k),
+                Point::Identity
+            );
+            assert_eq!(
+                secp256k1.mul_double(g, &(&k + 1u32), &secp256k1.negate(g), &k),
+                *g
+            );
+        }
+
+        #[test]
+        fn test_mul_base_with_table() {
+            use num_bigint::RandBigInt;
+
+            let mut rng = rand::thread_rng();
+            let toy = WeierstrassCurve::new(
+                BigUint::from(2u32),
+                BigUint::from(2u32),
+                BigUint::from(17u32),
+                BigUint::from(19u32),
+                Point::Coordinates(BigUint::from(5u32), BigUint::from(1u32)),
+            );
+            let secp256k1 = crate::curves::secp256k1::create_secp256k1_weierstrass();
+
+            for curve in [toy, secp256k1] {
+                let curve = curve.with_base_point_table();
+                let g = curve.base_point();
+                for scalar in [
+                    BigUint::from(0u32),
+                    BigUint::from(1u32),
+                    curve.order() - 1u32,
+                ] {
+                    assert_eq!(curve.mul_base(&scalar), curve.mul(g, &scalar));
+                }
+                for _ in 0..5 {
+                    let scalar = rng.gen_biguint_below(curve.order());
+                    assert_eq!(curve.mul_base(&scalar), curve.mul(g, &scalar));
+                }
+            }
+        }
+
+        #[test]
+        fn test_mul_blinded_matches_mul() {
+            use crate::curves::secp256k1::create_secp256k1_weierstrass;
+            use rand::rngs::StdRng;
+            use rand::SeedableRng;
+
+            let mut rng = StdRng::seed_from_u64(73);
+            let secp256k1 = create_secp256k1_weierstrass();
+            let point = secp256k1.mul(secp256k1.base_point(), &BigUint::from(7u32));
+            let scalar = rng.gen_biguint_below(secp256k1.order());
+            let expected = secp256k1.mul(&point, &scalar);
+            for _ in 0..4 {
+                assert_eq!(secp256k1.mul_blinded(&point, &scalar, &mut rng), expected);
+            }
+
+            // y^2 = x^3 + 2x + 5 over F_23, subgroup of order 11
+            let curve = WeierstrassCurve::new(
+                BigUint::from(2u32),
+                BigUint::from(5u32),
+                BigUint::from(23u32),
+                BigUint::from(11u32),
+                Point::Coordinates(BigUint::from(1u32), BigUint::from(10u32)),
+            )
+            .with_cofactor(BigUint::from(3u32));
+            for k in 0u32..11 {
+                let k = BigUint::from(k);
+                assert_eq!(
+                    curve.mul_blinded(curve.base_point(), &k, &mut rng),
+                    curve.mul(curve.base_point(), &k)
+                );
+            }
+        }
+
+        #[test]
+        fn test_mul_reduced() {
+            let curve = crate::curves::secp256k1::create_secp256k1_weierstrass();
+            let g = curve.base_point();
+            let oversized = curve.order() + BigUint::from(5u32);
+
+            assert_eq!(
+                curve.mul_reduced(g, &oversized),
+                curve.mul(g, &BigUint::from(5u32))
+            );
+        }
+    }
+
+    mod curve_properties {
+        use super::*;
+
+        #[test]
+        fn test_is_on_curve() {
+            let curve = create_test_curve();
+            let p = Point::Coordinates(BigUint::from(3u32), BigUint::from(1u32));
+            assert!(curve.is_on_curve(&p));
+
+            let not_on_curve = Point::Coordinates(BigUint::from(4u32), BigUint::from(2u32));
+            assert!(!curve.is_on_curve(&not_on_curve));
+        }
+
+        #[test]
+        fn test_is_in_subgroup_fast_path_on_cofactor_one() {
+            use num_bigint::RandBigInt;
+            use rand::rngs::StdRng;
+            use rand::SeedableRng;
+
+            let curve = crate::curves::secp256k1::create_secp256k1_weierstrass();
+            assert_eq!(curve.cofactor(), Some(&BigUint::from(1u32)));
+            let full_check =
+                |p: &Point| curve.is_on_curve(p) && curve.mul(p, curve.order()) == Point::Identity;
+            let mut rng = StdRng::seed_from_u64(100);
+
+            let mut points = vec![Point::Identity, curve.base_point().clone()];
+            points.extend((0..3).map(|_| curve.mul_base(&rng.gen_biguint_below(curve.order()))));
+            points.push(curve.random_point(&mut rng));
+            points.push(Point::Coordinates(BigUint::from(1u32), BigUint::from(1u32)));
+            for p in &points {
+                assert_eq!(curve.is_in_subgroup(p), full_check(p));
+            }
+            assert!(!curve.is_in_subgroup(points.last().unwrap()));
+        }
+
+        #[test]
+        fn test_is_in_subgroup_with_cofactor() {
+            // y^2 = x^3 + 2x + 5 over F_23 has 33 points: a subgroup of order 11
+            // and cofactor 3
+            let curve = WeierstrassCurve::new(
+                BigUint::from(2u32),
+                BigUint::from(5u32),
+                BigUint::from(23u32),
+                BigUint::from(11u32),
+                Point::Coordinates(BigUint::from(1u32), BigUint::from(10u32)),
+            )
+            .with_cofactor(BigUint::from(3u32));
+            let order_three = Point::Coordinates(BigUint::from(4u32), BigUint::from(10u32));
+            let order_33 = Point::Coordinates(BigUint::from(5u32), BigUint::from(5u32));
+
+            assert!(curve.is_in_subgroup(curve.base_point()));
+            assert!(curve.is_in_subgroup(&Point::Identity));
+            assert!(curve.is_on_curve(&order_three));
+            assert!(!curve.is_in_subgroup(&order_three));
+            assert!(!curve.is_in_subgroup(&order_33));
+            assert!(curve.is_in_subgroup(&curve.clear_cofactor(&order_33).unwrap()));
+
+            let off_curve = Point::Coordinates(BigUint::from(1u32), BigUint::from(11u32));
+            assert!(!curve.is_in_subgroup(&off_curve));
+        }
+
+        #[test]
+        fn test_is_in_subgroup_with_unstated_cofactor() {
+            // the 33-point curve again, built without `with_cofactor`: the
+            // cofactor is unknown, so the order check stays
+            let curve = WeierstrassCurve::new(
+                BigUint::from(2u32),
+                BigUint::from(5u32),
+                BigUint::from(23u32),
+                BigUint::from(11u32),
+                Point::Coordinates(BigUint::from(1u32), BigUint::from(10u32)),
+            );
+            assert_eq!(curve.cofactor(), None);
+            assert_eq!(curve.clear_cofactor(curve.base_point()), None);
+
+            assert!(curve.is_in_subgroup(curve.base_point()));
+            let order_three = Point::Coordinates(BigUint::from(4u32), BigUint::from(10u32));
+            let order_33 = Point::Coordinates(BigUint::from(5u32), BigUint::from(5u32));
+            assert!(!curve.is_in_subgroup(&order_three));
+            assert!(!curve.is_in_subgroup(&order_33));
+        }
+
+        #[test]
+        fn test_validate_public_key_full() {
+            let curve = crate::curves::secp256k1::create_secp256k1_weierstrass();
+            let public_key = curve.mul(curve.base_point(), &BigUint::from(0xC0FFEEu32));
+            assert_eq!(curve.validate_public_key_full(&public_key), Ok(()));
+
+            assert_eq!(
+                curve.validate_public_key_full(&Point::Identity),
+                Err(Error::Identity)
+            );
+
+            let off_curve = Point::Coordinates(BigUint::from(5u32), BigUint::from(5u32));
+            assert_eq!(
+                curve.validate_public_key_full(&off_curve),
+                Err(Error::NotOnCurve)
+            );
+
+            // (x + p, y) satisfies the curve equation modulo p but is not reduced
+            if let Point::Coordinates(x, y) = &public_key {
+                let unreduced = Point::Coordinates(x + curve.field_modulus(), y.clone());
+                assert_eq!(
+                    curve.validate_public_key_full(&unreduced),
+                    Err(Error::InvalidEncoding)
+                );
+            }
+        }
+
+        #[test]
+        fn test_new_checked() {
+            let params = |n: u32, g: Point| {
+                WeierstrassCurve::new_checked(
+                    BigUint::from(2u32),
+                    BigUint::from(2u32),
+                    BigUint::from(17u32),
+                    BigUint::from(n),
+                    g,
+                )
+            };
+            let g = Point::Coordinates(BigUint::from(5u32), BigUint::from(1u32));
+
+            assert!(params(19, g.clone()).is_ok());
+            assert_eq!(params(18, g).err(), Some(Error::NotInSubgroup));
+            assert_eq!(
+                params(
+                    19,
+                    Point::Coordinates(BigUint::from(4u32), BigUint::from(2u32))
+                )
+                .err(),
+                Some(Error::NotOnCurve)
+            );
+
+            // y^2 = x^3 - 3x + 2 = (x - 1)^2 (x + 2) over F_17
+            let singular = WeierstrassCurve::new_checked(
+                BigUint::from(14u32),
+                BigUint::from(2u32),
+                BigUint::from(17u32),
+                BigUint::from(19u32),
+                Point::Coordinates(BigUint::from(1u32), BigUint::from(0u32)),
+            );
+            assert_eq!(singular.err(), Some(Error::SingularCurve));
+        }
+
+        #[test]
+        fn test_is_nonsingular() {
+            assert!(create_test_curve().is_nonsingular());
+            assert!(crate::curves::secp256k1::create_secp256k1_weierstrass().is_nonsingular());
+
+            // y^2 = x^3 - 3x + 2 = (x - 1)^2 (x + 2) over F_17 has a node at (1, 0)
+            let node = WeierstrassCurve::new(
+                BigUint::from(14u32),
+                BigUint::from(2u32),
+                BigUint::from(17u32),
+                BigUint::from(19u32),
+                Point::Coordinates(BigUint::from(1u32), BigUint::from(0u32)),
+            );
+            assert!(!node.is_nonsingular());
+
+            // y^2 = x^3 has a cusp at the origin
+            let cusp = WeierstrassCurve::new(
+                BigUint::from(0u32),
+                BigUint::from(0u32),
+                BigUint::from(17u32),
+                BigUint::from(17u32),
+                Point::Coordinates(BigUint::from(1u32), BigUint::from(1u32)),
+            );
+            assert!(!cusp.is_nonsingular());
+        }
+
+        #[test]
+        fn test_point_reduces_coordinates() {
+            let curve = create_test_curve();
+            let (x, y) = (BigUint::from(5u32), BigUint::from(1u32));
+
+            let reduced = curve.point(x.clone(), y.clone());
+            assert_eq!(reduced, *curve.base_point());
+            assert_eq!(curve.point(&x + curve.p(), y.clone()), reduced);
+            assert_eq!(curve.point(x.clone(), &y + 3u32 * curve.p()), reduced);
+            assert_ne!(Point::Coordinates(&x + curve.p(), y), reduced);
+        }
+
+        #[test]
+        fn test_coefficient_accessors() {
+            let curve = crate::curves::secp256k1::create_secp256k1_weierstrass();
+            assert_eq!(curve.a(), &BigUint::from(0u32));
+            assert_eq!(curve.b(), &BigUint::from(7u32));
+        }
+    }
+
+    mod zero_a_fast_path {
+        use super::*;
+        use crate::curves::secp256k1::create_secp256k1_weierstrass;
+
+        /// The general formulas, without the `a == 0` shortcut
+        fn reference(curve: &WeierstrassCurve, x: &BigUint, y: &BigUint) -> (BigUint, BigUint) {
+            let p = curve.p();
+            let x2 = FiniteField::mul(x, x, p);
+            let numerator =
+                FiniteField::add(&FiniteField::mul(&BigUint::from(3u32), &x2, p), &curve.a, p);
+            let slope = FiniteField::div(&numerator, &FiniteField::add(y, y, p), p);
+            let rhs = FiniteField::add(
+                &FiniteField::add(
+                    &FiniteField::mul(&x2, x, p),
+                    &FiniteField::mul(&curve.a, x, p),
+                    p,
+                ),
+                &curve.b,
+                p,
+            );
+            (slope, rhs)
+        }
+
+        fn assert_matches_reference(curve: &WeierstrassCurve, points: impl Iterator<Item = Point>) {
+            for point in points {
+                let (x, y) = point.coordinates().unwrap();
+                let (slope, rhs) = reference(curve, x, y);
+                assert_eq!(curve.calculate_tangent_slope(x, y), slope);
+                assert_eq!(curve.curve_rhs(x), rhs);
+                assert!(curve.is_on_curve(&point));
+            }
+        }
+
+        #[test]
+        fn test_zero_a_matches_general_formulas() {
+            let curve = create_secp256k1_weierstrass();
+            assert!(curve.a.is_zero());
+            let points = (1u32..=8).map(|k| curve.mul(curve.base_point(), &BigUint::from(k)));
+            assert_matches_reference(&curve, points);
+        }
+
+        #[test]
+        fn test_nonzero_a_matches_general_formulas() {
+            let curve = create_test_curve();
+            assert!(!curve.a.is_zero());
+            let points = (1u32..=8).map(|k| curve.mul(curve.base_point(), &BigUint::from(k)));
+            assert_matches_reference(&curve, points);
+        }
+    }
+
+    mod prime_field {
+        use super::*;
+        use crate::curves::secp256k1::create_secp256k1_weierstrass;
+
+        /// F_p for a small prime, on native integers
+        struct SmallField {
+            p: u64,
+            modulus: BigUint,
+        }
+
+        impl SmallField {
+            fn new(p: u64) -> Self {
+                SmallField {
+                    p,
+                    modulus: BigUint::from(p),
+                }
+            }
+        }
+
+        impl PrimeField for SmallField {
+            type Element = u64;
+
+            fn modulus(&self) -> &BigUint {
+                &self.modulus
+            }
+
+            fn element(&self, value: &BigUint) -> u64 {
+                (value % self.p).try_into().unwrap()
+            }
+
+            fn to_biguint(&self, a: &u64) -> BigUint {
+                BigUint::from(*a)
+            }
+
+            fn is_zero(&self, a: &u64) -> bool {
+                *a == 0
+            }
+
+            fn add(&self, a: &u64, b: &u64) -> u64 {
+                (a + b) % self.p
+            }
+
+            fn sub(&self, a: &u64, b: &u64) -> u64 {
+                (a + self.p - b) % self.p
+            }
+
+            fn mul(&self, a: &u64, b: &u64) -> u64 {
+                a * b % self.p
+            }
+
+            fn inv(&self, a: &u64) -> u64 {
+                assert_ne!(*a, 0, "zero has no inverse");
+                (1..self.p).find(|b| a * b % self.p == 1).unwrap()
+            }
+        }
+
+        #[test]
+        fn test_explicit_biguint_field() {
+            let reference = create_secp256k1_weierstrass();
+            let curve: WeierstrassCurve<BigUintField> = WeierstrassCurve::with_field(
+                BigUintField::new(reference.field_modulus().clone()),
+                reference.a().clone(),
+                reference.b().clone(),
+                reference.order().clone(),
+                reference.base_point().clone(),
+            )
+            .with_cofactor(BigUint::from(1u32));
+
+            assert!(curve == reference);
+            assert_eq!(curve.field().modulus(), reference.field_modulus());
+            let k = BigUint::from(0xC0FFEEu32);
+            assert_eq!(curve.mul(curve.base_point(), &k), reference.mul_base(&k));
+            assert_eq!(
+                curve.mul(curve.base_point(), curve.order()),
+                Point::Identity
+            );
+        }
+
+        #[test]
+        fn test_group_law_is_independent_of_the_field() {
+            // the 33-point curve y^2 = x^3 + 2x + 5 over F_23, generated by (5, 5)
+            let g = Point::Coordinates(BigUint::from(5u32), BigUint::from(5u32));
+            let (a, b, n) = (
+                BigUint::from(2u32),
+                BigUint::from(5u32),
+                BigUint::from(33u32),
+            );
+            let small = WeierstrassCurve::with_field(
+                SmallField::new(23),
+                a.clone(),
+                b.clone(),
+                n.clone(),
+                g.clone(),
+            );
+            let reference = WeierstrassCurve::new(a, b, BigUint::from(23u32), n, g.clone());
+
+            let mut point = Point::Identity;
+            for k in 0u32..34 {
+                assert_eq!(small.mul(&g, &BigUint::from(k)), point);
+                assert!(small.is_on_curve(&point));
+                assert_eq!(small.double(&point), reference.double(&point));
+                assert_eq!(small.negate(&point), reference.negate(&point));
+                assert_eq!(
+                    small.add_with_info(&point, &g),
+                    reference.add_with_info(&point, &g)
+                );
+                point = reference.add(&point, &g);
+            }
+            assert!(!small.is_on_curve(&Point::Coordinates(
+                BigUint::from(1u32),
+                BigUint::from(1u32)
+            )));
+        }
+    }
+
+    mod twist {
+        use super::*;
+
+        // the 33-point curve y^2 = x^3 + 2x + 5 over F_23
+        fn create_curve() -> WeierstrassCurve {
+            WeierstrassCurve::new(
+                BigUint::from(2u32),
+                BigUint::from(5u32),
+                BigUint::from(23u32),
+                BigUint::from(11u32),
+                Point::Coordinates(BigUint::from(1u32), BigUint::from(10u32)),
+            )
+            .with_cofactor(BigUint::from(3u32))
+        }
+
+        #[test]
+        fn test_twist_order() {
+            let curve = create_curve();
+            let p = curve.field_modulus();
+
+            // count the twist d·y^2 = x^3 + 2x + 5 directly: an x where the
+            // right side is a non-residue gives two points there instead of
+            // none, and a residue gives none instead of two
+            let mut twist_points = 1u32;
+            for x in 0u32..23 {
+                twist_points +=
+                    match FiniteField::jacobi_symbol(&curve.curve_rhs(&BigUint::from(x)), p) {
+                        -1 => 2,
+                        0 => 1,
+                        _ => 0,
+                    };
+            }
+
+            assert_eq!(curve.twist_order(), Some(BigUint::from(twist_points)));
+            assert_eq!(
+                curve.count_points() + curve.twist_order().unwrap(),
+                (p << 1u32) + 2u32
+            );
+            assert_eq!(curve.twist_order(), Some(BigUint::from(15u32)));
+        }
+
+        #[test]
+        fn test_is_twist_secure() {
+            let curve = create_curve();
+            let factors = [BigUint::from(3u32), BigUint::from(5u32)];
+
+            assert!(curve.is_twist_secure(&factors, 3));
+            assert!(!curve.is_twist_secure(&factors, 4));
+            // not a factorization of 15
+            assert!(!curve.is_twist_secure(&[BigUint::from(5u32)], 3));
+            assert!(!curve.is_twist_secure(&[], 0));
+        }
+    }
+
+    mod generate {
+        use super::*;
+        use crate::ecdsa::ECDSA;
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        #[test]
+        fn test_count_points() {
+            // the 33-point curve y^2 = x^3 + 2x + 5 over F_23
+            let curve = WeierstrassCurve::new(
+                BigUint::from(2u32),
+                BigUint::from(5u32),
+                BigUint::from(23u32),
+                BigUint::from(11u32),
+                Point::Coordinates(BigUint::from(1u32), BigUint::from(10u32)),
+            );
+            assert_eq!(curve.count_points(), BigUint::from(33u32));
+            assert_eq!(
+                largest_prime_factor(&BigUint::from(33u32)),
+                BigUint::from(11u32)
+            );
+            assert_eq!(
+                largest_prime_factor(&BigUint::from(64u32)),
+                BigUint::from(2u32)
+            );
+        }
+
+        #[test]
+        fn test_generated_curve_signs_and_verifies() {
+            let p = BigUint::from(10007u32);
+            let mut rng = StdRng::seed_from_u64(67);
+            let curve = WeierstrassCurve::generate(&p, &mut rng);
+
+            assert!(curve.is_nonsingular());
+            assert!(curve.is_on_curve(curve.base_point()));
+            assert!(FiniteField::is_probable_prime_with_rng(
+                curve.order(),
+                16,
+                &mut rng
+            ));
+            assert_eq!(
+                curve.mul(curve.base_point(), curve.order()),
+                Point::Identity
+            );
+            assert_eq!(
+                curve.order() * curve.cofactor().unwrap(),
+                curve.count_points()
+            );
+
+            let ecdsa = ECDSA::new(curve);
+            for message in 1u32..=5 {
+                let message = BigUint::from(message);
+                let (private_key, public_key) = ecdsa.generate_keypair_with_rng(&mut rng);
+                let signature = ecdsa
+                    .sign_with_rng(&message, &private_key, &mut rng)
+                    .unwrap();
+                assert!(ecdsa.verify(&message, &signature, &public_key));
+                assert!(!ecdsa.verify(&(message + 1u32), &signature, &public_key));
+            }
+        }
+
+        #[test]
+        fn test_generate_skips_non_cyclic_groups() {
+            // over F_5 and F_7 many curves have 4 or 8 points, where the group
+            // can be Z_2 x Z_2 and no point reaches the full order
+            for p in [5u32, 7] {
+                let p = BigUint::from(p);
+                for seed in 0..20 {
+                    let mut rng = StdRng::seed_from_u64(seed);
+                    let curve = WeierstrassCurve::generate(&p, &mut rng);
+
+                    assert!(curve.order() > curve.cofactor().unwrap());
+                    assert_ne!(*curve.base_point(), Point::Identity);
+                    assert_eq!(
+                        curve.mul(curve.base_point(), curve.order()),
+                        Point::Identity
+                    );
+                    assert_eq!(
+                        curve.order() * curve.cofactor().unwrap(),
+                        curve.count_points()
+                    );
+                }
+            }
+        }
+    }
+
+    mod discrete_log {
+        use super::*;
+        use crate::curves::secp256k1::create_secp256k1_weierstrass;
+
+        #[test]
+        fn test_recovers_small_exponents() {
+            let curve = create_test_curve();
+            let g = curve.base_point();
+            let max = BigUint::from(18u32);
+
+            for x in 0u32..19 {
+                let x = BigUint::from(x);
+                let target = curve.mul(g, &x);
+                assert_eq!(curve.discrete_log(g, &target, &max), Some(x));
+            }
+        }
+
+        #[test]
+        fn test_respects_bound() {
+            let curve = create_test_curve();
+            let g = curve.base_point();
+            let target = curve.mul(g, &BigUint::from(15u32));
+
+            assert_eq!(curve.discrete_log(g, &target, &BigUint::from(10u32)), None);
+            assert_eq!(
+                curve.discrete_log(g, &target, &BigUint::from(15u32)),
+                Some(BigUint::from(15u32))
+            );
+        }
+
+        #[test]
+        fn test_secp256k1_small_exponent() {
+            let curve = create_secp256k1_weierstrass();
+            let x = BigUint::from(123_456u32);
+            let target = curve.mul(curve.base_point(), &x);
+            assert_eq!(
+                curve.discrete_log(curve.base_point(), &target, &BigUint::from(1u32 << 20)),
+                Some(x)
+            );
+        }
+    }
+
+    mod point_order {
+        use super::*;
+
+        // y^2 = x^3 + 2x + 5 over F_23: 33 points, with elements of order 1, 3,
+        // 11 and 33
+        fn create_cofactor_curve() -> WeierstrassCurve {
+            WeierstrassCurve::new(
+                BigUint::from(2u32),
+                BigUint::from(5u32),
+                BigUint::from(23u32),
+                BigUint::from(11u32),
+                Point::Coordinates(BigUint::from(1u32), BigUint::from(10u32)),
+            )
+            .with_cofactor(BigUint::from(3u32))
+        }
+
+        fn known_orders() -> [(Point, u32); 4] {
+            let point = |x: u32, y: u32| Point::Coordinates(BigUint::from(x), BigUint::from(y));
+            [
+                (Point::Identity, 1),
+                (point(4, 10), 3),
+                (point(1, 10), 11),
+                (point(5, 5), 33),
+            ]
+        }
+
+        #[test]
+        fn test_point_order() {
+            let curve = create_cofactor_curve();
+            let max = BigUint::from(33u32);
+
+            for (point, order) in known_orders() {
+                assert_eq!(curve.point_order(&point, &max), Some(BigUint::from(order)));
+            }
+
+            // the order-11 base point is out of reach with a bound of 5
+            let bound = BigUint::from(5u32);
+            assert_eq!(curve.point_order(curve.base_point(), &bound), None);
+            assert_eq!(
+                curve.point_order(&known_orders()[1].0, &bound),
+                Some(BigUint::from(3u32))
+            );
+
+            let off_curve = Point::Coordinates(BigUint::from(1u32), BigUint::from(11u32));
+            assert_eq!(curve.point_order(&off_curve, &max), None);
+        }
+
+        #[test]
+        fn test_point_order_with_unknown_cofactor() {
+            let curve = WeierstrassCurve::new(
+                BigUint::from(2u32),
+                BigUint::from(5u32),
+                BigUint::from(23u32),
+                BigUint::from(11u32),
+                Point::Coordinates(BigUint::from(1u32), BigUint::from(10u32)),
+            );
+            let max = BigUint::from(33u32);
+
+            for (point, order) in known_orders() {
+                assert_eq!(curve.point_order(&point, &max), Some(BigUint::from(order)));
+            }
+            assert_eq!(curve.twist_order(), None);
+        }
+
+        #[test]
+        fn test_point_order_with_factors() {
+            let curve = create_cofactor_curve();
+            let factors = [(BigUint::from(3u32), 1), (BigUint::from(11u32), 1)];
+
+            for (point, order) in known_orders() {
+                assert_eq!(
+                    curve.point_order_with_factors(&point, &factors),
+                    Some(BigUint::from(order))
+                );
+            }
+
+            // 11 does not annihilate a point of order 33
+            let partial = [(BigUint::from(11u32), 1)];
+            assert_eq!(
+                curve.point_order_with_factors(&known_orders()[3].0, &partial),
+                None
+            );
+        }
+
+        #[test]
+        fn test_secp256k1_base_point_order() {
+            let curve = crate::curves::secp256k1::create_secp256k1_weierstrass();
+            let factors = [(curve.order().clone(), 1)];
+            assert_eq!(
+                curve.point_order_with_factors(curve.base_point(), &factors),
+                Some(curve.order().clone())
+            );
+        }
+    }
+
+    mod glv {
+        use super::*;
+        use crate::curves::secp256k1::create_secp256k1_weierstrass;
+        use crate::curves::secp256r1::create_secp256r1_weierstrass;
+        use num_bigint::BigInt;
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        #[test]
+        fn test_mul_glv_matches_mul() {
+            let curve = create_secp256k1_weierstrass();
+            let point = curve.mul(curve.base_point(), &BigUint::from(0xC0FFEEu32));
+            let mut rng = StdRng::seed_from_u64(60);
+
+            let mut scalars = vec![
+                BigUint::from(0u32),
+                BigUint::from(1u32),
+                curve.order() - 1u32,
+                curve.order().clone(),
+                curve.order() + 5u32,
+            ];
+            scalars.extend((0..8).map(|_| rng.gen_biguint_below(curve.order())));
+
+            for scalar in &scalars {
+                assert_eq!(
+                    curve.mul_glv(&point, scalar),
+                    curve.mul(&point, &(scalar % curve.order()))
+                );
+            }
+        }
+
+        #[test]
+        fn test_decomposition_halves_scalars() {
+            let curve = create_secp256k1_weierstrass();
+            let endomorphism = curve.endomorphism().unwrap();
+            let mut rng = StdRng::seed_from_u64(61);
+
+            for _ in 0..32 {
+                let k = rng.gen_biguint_below(curve.order());
+                let (k1, k2) = endomorphism.decompose(&k, curve.order());
+                assert!(k1.bits() <= 129 && k2.bits() <= 129);
+
+                let n = BigInt::from(curve.order().clone());
+                let lambda = BigInt::from(endomorphism.lambda.clone());
+                let recombined = ((k1 + k2 * lambda) % &n + &n) % &n;
+                assert_eq!(recombined.magnitude(), &k);
+            }
+        }
+
+        #[test]
+        fn test_endomorphism_descriptor() {
+            assert!(create_secp256k1_weierstrass().endomorphism().is_some());
+            assert!(create_secp256r1_weierstrass().endomorphism().is_none());
+            assert!(create_test_curve().endomorphism().is_none());
+        }
+
+        #[test]
+        fn test_mul_glv_falls_back_without_endomorphism() {
+            let curve = create_test_curve();
+            assert!(curve.endomorphism().is_none());
+            for k in 0u32..20 {
+                let k = BigUint::from(k);
+                assert_eq!(
+                    curve.mul_glv(curve.base_point(), &k),
+                    curve.mul(curve.base_point(), &k)
+                );
+            }
+        }
+    }
+
+    mod mul_many {
+        use super::*;
+        use crate::curves::secp256k1::create_secp256k1_weierstrass;
+        use num_bigint::RandBigInt;
+
+        #[test]
+        fn test_mul_many_matches_sequential() {
+            let mut rng = rand::thread_rng();
+            let curve = create_secp256k1_weierstrass();
+            let point = curve.mul(curve.base_point(), &BigUint::from(7u32));
+            let mut scalars = vec![BigUint::from(0u32), curve.order().clone()];
+            scalars.extend((0..6).map(|_| rng.gen_biguint_below(curve.order())));
+
+            let sequential: Vec<Point> = scalars.iter().map(|s| curve.mul(&point, s)).collect();
+            assert_eq!(curve.mul_many(&point, &scalars), sequential);
+            assert!(curve.mul_many(&point, &[]).is_empty());
+        }
+
+        #[test]
+        fn test_mul_sum_matches_separate_products() {
+            let mut rng = rand::thread_rng();
+            let curve = create_secp256k1_weierstrass();
+            let terms: Vec<(Point, BigUint)> = (1u32..5)
+                .map(|k| {
+                    let point = curve.mul(curve.base_point(), &BigUint::from(k));
+                    (point, rng.gen_biguint_below(curve.order()))
+                })
+                .chain([(Point::Identity, BigUint::from(3u32))])
+                .collect();
+
+            let expected = terms.iter().fold(Point::Identity, |acc, (p, s)| {
+                curve.add(&acc, &curve.mul(p, s))
+            });
+            assert_eq!(curve.mul_sum(&terms), expected);
+            assert_eq!(curve.mul_sum(&[]), Point::Identity);
+        }
+    }
+
+    mod sec1 {
+        use super::*;
+        use crate::curves::secp256k1::create_secp256k1_weierstrass;
+
+        #[test]
+        fn test_compressed_round_trip() {
+            let curve = create_secp256k1_weierstrass();
+            for k in 1u32..6 {
+                let point = curve.mul(curve.base_point(), &BigUint::from(k));
+                let bytes = curve.to_sec1_compressed(&point);
+
+                assert_eq!(bytes.len(), 33);
+                assert_eq!(curve.from_sec1_compressed(&bytes), Ok(point));
+            }
+        }
+
+        #[test]
+        fn test_decode_base_point() {
+            let curve = create_secp256k1_weierstrass();
+            let mut bytes = vec![0x02];
+            bytes.extend(
+                BigUint::parse_bytes(
+                    b"79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+                    16,
+                )
+                .unwrap()
+                .to_bytes_be(),
+            );
+
+            assert_eq!(
+                curve.from_sec1_compressed(&bytes).as_ref(),
+                Ok(curve.base_point())
+            );
+        }
+
+        #[test]
+        fn test_decode_rejects_malformed_input() {
+            let curve = create_secp256k1_weierstrass();
+            let mut bytes = curve.to_sec1_compressed(curve.base_point());
+
+            assert_eq!(
+                curve.from_sec1_compressed(&bytes[..32]),
+                Err(Error::InvalidEncoding)
+            );
+
+            bytes[0] = 0x04;
+            assert_eq!(
+                curve.from_sec1_compressed(&bytes),
+                Err(Error::InvalidEncoding)
+            );
+
+            // x = 5 gives 5^3 + 7 = 132, a non-residue modulo the secp256k1 prime
+            let mut not_on_curve = vec![0x02];
+            not_on_curve.extend(to_bytes_be_padded(&BigUint::from(5u32), 32));
+            assert_eq!(
+                curve.from_sec1_compressed(&not_on_curve),
+                Err(Error::NotOnCurve)
+            );
+        }
+    }
+
+    mod sec1_non_residues {
+        use super::*;
+
+        #[test]
+        fn test_x_without_point_is_not_on_curve() {
+            // y^2 = x^3 + 2x + 5 over F_23
+            let curve = WeierstrassCurve::new(
+                BigUint::from(2u32),
+                BigUint::from(5u32),
+                BigUint::from(23u32),
+                BigUint::from(11u32),
+                Point::Coordinates(BigUint::from(1u32), BigUint::from(10u32)),
+            );
+            for x in 0u8..23 {
+                let has_point = FiniteField::sqrt(&curve.curve_rhs(&BigUint::from(x)), curve.p());
+                let decoded = curve.from_sec1_compressed(&[0x02, x]);
+                match has_point {
+                    Some(_) => assert!(decoded.is_ok()),
+                    None => assert_eq!(decoded, Err(Error::NotOnCurve), "x = {}", x),
+                }
+            }
+        }
+    }
+
+    mod hash_to_curve {
+        use super::*;
+        use crate::curves::secp256k1::create_secp256k1_weierstrass;
+
+        #[test]
+        fn test_hash_to_curve_is_on_curve() {
+            let toy = create_test_curve();
+            let secp256k1 = create_secp256k1_weierstrass();
+
+            for input in [&b""[..], b"a", b"hello world", b"ecc-rust", &[0xffu8; 64]] {
+                assert!(toy.is_on_curve(&toy.hash_to_curve(input)));
+                assert!(secp256k1.is_on_curve(&secp256k1.hash_to_curve(input)));
+            }
+        }
+
+        #[test]
+        fn test_hash_to_curve_is_deterministic() {
+            let curve = create_secp256k1_weierstrass();
+            assert_eq!(
+                curve.hash_to_curve(b"message"),
+                curve.hash_to_curve(b"message")
+            );
+            assert_ne!(
+                curve.hash_to_curve(b"message"),
+                curve.hash_to_curve(b"other message")
+            );
+        }
+    }
+
+    mod random_point {
+        use super::*;
+        use crate::curves::secp256k1::create_secp256k1_weierstrass;
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        #[test]
+        fn test_random_points_are_on_curve() {
+            let curve = create_secp256k1_weierstrass();
+            let mut rng = StdRng::seed_from_u64(5);
+            let points: Vec<Point> = (0..10).map(|_| curve.random_point(&mut rng)).collect();
+
+            for (i, point) in points.iter().enumerate() {
+                assert!(curve.is_on_curve(point));
+                assert!(!points[..i].contains(point));
+            }
+        }
+
+        #[test]
+        fn test_random_points_cover_small_curve() {
+            // y^2 = x^3 + 2x + 2 over F_17 has 18 affine points
+            let curve = create_test_curve();
+            let mut rng = StdRng::seed_from_u64(5);
+            let mut seen = Vec::new();
+            for _ in 0..500 {
+                let point = curve.random_point(&mut rng);
+                assert!(curve.is_on_curve(&point));
+                if !seen.contains(&point) {
+                    seen.push(point);
+                }
+            }
+            assert_eq!(seen.len(), 18);
+        }
+    }
+
+    mod montgomery_conversion {
+        use super::*;
+        use crate::curves::secp256k1::create_secp256k1_weierstrass;
+
+        // y^2 = x^3 + 99x + 1 over F_101, the Weierstrass form of
+        // y^2 = x^3 + 3x^2 + x, with a base point of order 24
+        fn create_convertible_curve() -> WeierstrassCurve {
+            WeierstrassCurve::new(
+                BigUint::from(99u32),
+                BigUint::from(1u32),
+                BigUint::from(101u32),
+                BigUint::from(24u32),
+                Point::Coordinates(BigUint::from(25u32), BigUint::from(27u32)),
+            )
+        }
+
+        #[test]
+        fn test_points_convert_back_and_forth() {
+            let curve = create_convertible_curve();
+            assert!(curve.is_on_curve(curve.base_point()));
+
+            let (montgomery, to_montgomery) = curve.to_montgomery().unwrap();
+            let (weierstrass, to_weierstrass) = montgomery.to_weierstrass();
+            assert!(montgomery.is_on_curve(montgomery.base_point()));
+            assert_eq!(weierstrass.a, curve.a);
+            assert_eq!(weierstrass.b, curve.b);
+
+            for k in 1u32..24 {
+                let k = BigUint::from(k);
+                let point = curve.mul(curve.base_point(), &k);
+                let converted = to_montgomery(&point);
+
+                assert!(montgomery.is_on_curve(&converted));
+                assert_eq!(converted, montgomery.mul(montgomery.base_point(), &k));
+
+                let back = to_weierstrass(&converted);
+                assert!(curve.is_on_curve(&back));
+                assert_eq!(back, point);
+            }
+            assert_eq!(to_montgomery(&Point::Identity), Point::Identity);
+        }
+
+        #[test]
+        fn test_prime_order_curve_has_no_montgomery_form() {
+            assert!(create_secp256k1_weierstrass().to_montgomery().is_none());
+        }
+
+        #[test]
+        fn test_singular_curve_has_no_montgomery_form() {
+            // y^2 = x^3 - 3x + 2 = (x - 1)^2 (x + 2) over F_17: at the double
+            // root 1, 3α^2 + a = 0 has no inverse
+            for (a, b) in [(14u32, 2u32), (0, 0)] {
+                let singular = WeierstrassCurve::new(
+                    BigUint::from(a),
+                    BigUint::from(b),
+                    BigUint::from(17u32),
+                    BigUint::from(19u32),
+                    Point::Identity,
+                );
+                assert!(singular.to_montgomery().is_none());
+            }
+        }
+    }
+}
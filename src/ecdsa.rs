@@ -1,21 +1,45 @@
-use crate::{EllipticCurve, FiniteField, Point};
+use crate::curves::by_name;
+use crate::encoding::{byte_length, decode_hex, to_bytes_be_padded};
+use crate::error::Error;
+use crate::hmac::hmac_sha256;
+use crate::{EllipticCurve, FiniteField, Point, PrivateKey, ScalarField, WeierstrassCurve};
+use alloc::vec;
+use alloc::vec::Vec;
 use log::{debug, info, warn};
 use num_bigint::{BigUint, RandBigInt};
+use num_traits::Zero;
+#[cfg(feature = "std")]
 use rand::thread_rng;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+#[cfg(feature = "std")]
+use std::io::Read;
 
 pub struct ECDSA<T: EllipticCurve> {
     curve: T,
+    scalars: ScalarField,
 }
 
 impl<T: EllipticCurve> ECDSA<T> {
     pub fn new(curve: T) -> Self {
         debug!("Creating new ECDSA instance");
-        ECDSA { curve }
+        let scalars = ScalarField::new(curve.order().clone());
+        ECDSA { curve, scalars }
     }
 
-    pub fn generate_keypair(&self) -> (BigUint, Point) {
+    /// Returns the curve this instance signs over
+    pub fn curve(&self) -> &T {
+        &self.curve
+    }
+
+    #[cfg(feature = "std")]
+    pub fn generate_keypair(&self) -> (PrivateKey, Point) {
+        self.generate_keypair_with_rng(&mut thread_rng())
+    }
+
+    pub fn generate_keypair_with_rng<R: RngCore>(&self, rng: &mut R) -> (PrivateKey, Point) {
         debug!("Generating new keypair");
-        let private_key = self.generate_random_private_key();
+        let private_key = PrivateKey::new(self.generate_random_private_key(rng));
         let public_key = self.generate_public_key(&private_key);
 
         self.validate_public_key(&public_key);
@@ -23,22 +47,125 @@ impl<T: EllipticCurve> ECDSA<T> {
         (private_key, public_key)
     }
 
+    /// Loads an existing private key from big-endian bytes and derives its
+    /// public key. The scalar must lie in `[1, order)`.
+    pub fn import_private_key(&self, bytes: &[u8]) -> Result<(PrivateKey, Point), Error> {
+        let scalar = BigUint::from_bytes_be(bytes);
+        if scalar.is_zero() || &scalar >= self.curve.order() {
+            return Err(Error::InvalidScalar);
+        }
+
+        let private_key = PrivateKey::new(scalar);
+        let public_key = self.generate_public_key(&private_key);
+        Ok((private_key, public_key))
+    }
+
+    /// Deterministically derives a private key in `[1, order)` from `seed`.
+    ///
+    /// Hashes `seed || counter` with SHA-256, extending the output with more
+    /// blocks if the order is wider than a digest, and keeps the leftmost bits
+    /// up to the bit length of the order. Candidates that are zero or not
+    /// below the order are rejected and the counter incremented, so the key
+    /// is uniform in its range. The same seed always gives the same key; the
+    /// seed needs as much entropy as the key it stands for.
+    pub fn derive_private_key_from_seed(&self, seed: &[u8]) -> BigUint {
+        let order = self.curve.order();
+        let len = byte_length(order);
+
+        let mut counter = 0u32;
+        loop {
+            let mut t = Vec::with_capacity(len + 32);
+            let mut block = 0u32;
+            while t.len() < len {
+                let digest = Sha256::new()
+                    .chain_update(seed)
+                    .chain_update(counter.to_be_bytes())
+                    .chain_update(block.to_be_bytes())
+                    .finalize();
+                t.extend_from_slice(&digest);
+                block += 1;
+            }
+
+            let candidate = bits2int(&t, order.bits() as usize);
+            if !candidate.is_zero() && &candidate < order {
+                return candidate;
+            }
+            counter += 1;
+        }
+    }
+
     pub fn generate_public_key(&self, private_key: &BigUint) -> Point {
         debug!("Generating public key from private key");
-        self.curve.mul(self.curve.base_point(), private_key)
+        self.curve.mul_base(private_key)
     }
 
+    #[cfg(feature = "std")]
     pub fn sign(
         &self,
         message: &BigUint,
         private_key: &BigUint,
-    ) -> Result<(BigUint, BigUint), &'static str> {
-        self.validate_input(message, private_key)?;
+    ) -> Result<(BigUint, BigUint), Error> {
+        self.sign_with_rng(message, private_key, &mut thread_rng())
+    }
+
+    /// Signs with a random nonce. If the order is composite the nonce may
+    /// share a factor with it, which fails with `Error::NoInverse`; that can't
+    /// happen on a curve of prime order, and otherwise signing again draws a
+    /// new nonce.
+    pub fn sign_with_rng<R: RngCore>(
+        &self,
+        message: &BigUint,
+        private_key: &BigUint,
+        rng: &mut R,
+    ) -> Result<(BigUint, BigUint), Error> {
+        let message = &self.normalize_input(message, private_key)?;
         debug!("Signing message");
-        let k = self.generate_random_private_key();
+        let k = self.generate_random_private_key(rng);
+        self.sign_with_k(message, private_key, &k)
+    }
+
+    /// Signs with a caller-supplied nonce `k`, e.g. to reproduce published test
+    /// vectors. `k` must lie in `[1, order)`, and a nonce giving `r = 0` or
+    /// `s = 0`, or one with no inverse modulo the order, is rejected; retry
+    /// with a different `k`. Never reuse a `k` across messages: two signatures
+    /// with the same nonce reveal the private key.
+    pub fn sign_with_nonce(
+        &self,
+        message: &BigUint,
+        private_key: &BigUint,
+        k: &BigUint,
+    ) -> Result<(BigUint, BigUint), Error> {
+        let message = &self.normalize_input(message, private_key)?;
+        self.sign_with_k(message, private_key, k)
+    }
+
+    /// Signs with a hedged nonce: `k` comes from the RFC 6979 HMAC-DRBG seeded
+    /// with the private key, the message and 32 fresh bytes from `rng`
+    /// (RFC 6979, section 3.6).
+    ///
+    /// A weak or repeated `rng` still yields a deterministic, safe `k`, while the
+    /// fresh bytes keep an attacker from replaying a signing operation with an
+    /// induced fault to recover the key.
+    pub fn sign_hedged<R: RngCore>(
+        &self,
+        message: &BigUint,
+        private_key: &BigUint,
+        rng: &mut R,
+    ) -> Result<(BigUint, BigUint), Error> {
+        let message = &self.normalize_input(message, private_key)?;
+        debug!("Signing message with a hedged nonce");
+        let mut extra = [0u8; 32];
+        rng.fill_bytes(&mut extra);
+        let k = self.hedged_nonce(message, private_key, &extra);
         self.sign_with_k(message, private_key, &k)
     }
 
+    /// Checks a signature over `message` against `public_key`.
+    ///
+    /// Malformed keys and out-of-range `r`, `s` are rejected up front. The
+    /// final comparison of `x(R) mod n` with `r` goes through
+    /// `FiniteField::ct_eq`, so its timing doesn't reveal how much of an
+    /// invalid signature matched.
     pub fn verify(
         &self,
         message: &BigUint,
@@ -48,26 +175,179 @@ impl<T: EllipticCurve> ECDSA<T> {
         debug!("Verifying signature");
         let (r, s) = signature;
 
-        if !self.is_valid_signature(r, s) {
+        if !self.is_valid_public_key(public_key) || !self.is_valid_signature(r, s) {
             return false;
         }
 
-        let s_inv = FiniteField::inv_mul(s, self.curve.order());
-        let u1 = FiniteField::mul(message, &s_inv, self.curve.order());
-        let u2 = FiniteField::mul(r, &s_inv, self.curve.order());
+        let message = &self.curve.reduce_scalar(message);
+        // s is public, and over a composite order it may have no inverse
+        let Ok(s_inv) = self.scalars.try_inv(s) else {
+            warn!("Invalid signature: s has no inverse modulo the order");
+            return false;
+        };
+        let u1 = self.scalars.mul(message, &s_inv);
+        let u2 = self.scalars.mul(r, &s_inv);
         let point = self.calculate_verification_point(&u1, &u2, public_key);
 
         self.is_signature_valid(point, r)
     }
 
-    fn validate_input(&self, message: &BigUint, private_key: &BigUint) -> Result<(), &'static str> {
+    /// Verifies a batch of `(message, signature, public_key)` entries and
+    /// returns `true` only if every signature is valid.
+    ///
+    /// A signature doesn't say which of the two points with x-coordinate `r`
+    /// is its nonce point, so the entries can't be summed into one equation
+    /// and each is checked with `verify`. Signatures that carry a recovery id,
+    /// such as those from `sign_eth`, can use `verify_batch_recoverable`
+    /// instead.
+    pub fn verify_batch(&self, items: &[(BigUint, (BigUint, BigUint), Point)]) -> bool {
+        debug!("Verifying a batch of {} signatures", items.len());
+        items
+            .iter()
+            .all(|(message, signature, public_key)| self.verify(message, signature, public_key))
+    }
+
+    /// Hashes `msg` with SHA-256 and signs the digest, truncated to the bit
+    /// length of the order and reduced modulo the order.
+    #[cfg(feature = "std")]
+    pub fn sign_message(
+        &self,
+        msg: &[u8],
+        private_key: &BigUint,
+    ) -> Result<(BigUint, BigUint), Error> {
+        self.sign_message_with_rng(msg, private_key, &mut thread_rng())
+    }
+
+    pub fn sign_message_with_rng<R: RngCore>(
+        &self,
+        msg: &[u8],
+        private_key: &BigUint,
+        rng: &mut R,
+    ) -> Result<(BigUint, BigUint), Error> {
+        self.sign_with_rng(&self.hash_message(msg), private_key, rng)
+    }
+
+    /// Like `sign_message`, but streams the message from `reader` through the
+    /// hasher instead of holding it in memory
+    #[cfg(feature = "std")]
+    pub fn sign_reader<R: Read>(
+        &self,
+        reader: R,
+        private_key: &BigUint,
+    ) -> Result<(BigUint, BigUint), Error> {
+        self.sign_reader_with_rng(reader, private_key, &mut thread_rng())
+    }
+
+    #[cfg(feature = "std")]
+    pub fn sign_reader_with_rng<R: Read, G: RngCore>(
+        &self,
+        reader: R,
+        private_key: &BigUint,
+        rng: &mut G,
+    ) -> Result<(BigUint, BigUint), Error> {
+        let message = self.hash_reader(reader).map_err(|_| Error::ReadFailed)?;
+        self.sign_with_rng(&message, private_key, rng)
+    }
+
+    /// Verifies a signature made by `sign_message` over the raw bytes `msg`
+    pub fn verify_message(
+        &self,
+        msg: &[u8],
+        signature: &(BigUint, BigUint),
+        public_key: &Point,
+    ) -> bool {
+        self.verify(&self.hash_message(msg), signature, public_key)
+    }
+
+    /// Encodes a signature as `r || s`, each component left-padded with zeros
+    /// to the byte length of the curve order.
+    pub fn signature_to_bytes(&self, signature: &(BigUint, BigUint)) -> Vec<u8> {
+        let len = byte_length(self.curve.order());
+        let mut bytes = to_bytes_be_padded(&signature.0, len);
+        bytes.extend(to_bytes_be_padded(&signature.1, len));
+        bytes
+    }
+
+    /// Decodes a signature produced by `signature_to_bytes`, rejecting inputs of
+    /// the wrong length and components not below the curve order.
+    pub fn signature_from_bytes(&self, bytes: &[u8]) -> Result<(BigUint, BigUint), Error> {
+        let len = byte_length(self.curve.order());
+        if bytes.len() != 2 * len {
+            return Err(Error::InvalidEncoding);
+        }
+
+        let r = BigUint::from_bytes_be(&bytes[..len]);
+        let s = BigUint::from_bytes_be(&bytes[len..]);
+        if &r >= self.curve.order() || &s >= self.curve.order() {
+            return Err(Error::InvalidEncoding);
+        }
+
+        Ok((r, s))
+    }
+
+    /// The ECDSA hashing step: SHA-256, keep the leftmost bits up to the bit
+    /// length of the order, then reduce modulo the order
+    fn hash_message(&self, msg: &[u8]) -> BigUint {
+        self.digest_to_scalar(&Sha256::digest(msg))
+    }
+
+    #[cfg(feature = "std")]
+    fn hash_reader<R: Read>(&self, mut reader: R) -> std::io::Result<BigUint> {
+        let mut hasher = Sha256::new();
+        let mut buffer = [0u8; 8192];
+        loop {
+            match reader.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(n) => hasher.update(&buffer[..n]),
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(self.digest_to_scalar(&hasher.finalize()))
+    }
+
+    fn digest_to_scalar(&self, digest: &[u8]) -> BigUint {
+        self.curve
+            .reduce_scalar(&bits2int(digest, self.curve.order().bits() as usize))
+    }
+
+    /// Checks the private key and reduces the message modulo the order, as the
+    /// ECDSA specification does with the hash integer
+    fn normalize_input(&self, message: &BigUint, private_key: &BigUint) -> Result<BigUint, Error> {
         if private_key >= self.curve.order() {
-            return Err("Private key must be less than the order of the curve");
+            return Err(Error::InvalidScalar);
         }
-        if message >= self.curve.order() {
-            return Err("Message must be less than the order of the curve");
+        Ok(self.curve.reduce_scalar(message))
+    }
+
+    /// RFC 6979 nonce generation with `extra` as the additional data `k'`
+    fn hedged_nonce(&self, message: &BigUint, private_key: &BigUint, extra: &[u8]) -> BigUint {
+        let order = self.curve.order();
+        let len = byte_length(order);
+        let x = to_bytes_be_padded(private_key, len);
+        let h = to_bytes_be_padded(message, len);
+
+        let mut v = [0x01u8; 32];
+        let mut k = [0x00u8; 32];
+        k = hmac_sha256(&k, &[&v, &[0x00], &x, &h, extra]);
+        v = hmac_sha256(&k, &[&v]);
+        k = hmac_sha256(&k, &[&v, &[0x01], &x, &h, extra]);
+        v = hmac_sha256(&k, &[&v]);
+
+        loop {
+            let mut t = Vec::with_capacity(len + 32);
+            while t.len() < len {
+                v = hmac_sha256(&k, &[&v]);
+                t.extend_from_slice(&v);
+            }
+
+            let candidate = bits2int(&t, order.bits() as usize);
+            if !candidate.is_zero() && &candidate < order {
+                return candidate;
+            }
+            k = hmac_sha256(&k, &[&v, &[0x00]]);
+            v = hmac_sha256(&k, &[&v]);
         }
-        Ok(())
     }
 
     fn sign_with_k(
@@ -75,21 +355,27 @@ impl<T: EllipticCurve> ECDSA<T> {
         message: &BigUint,
         private_key: &BigUint,
         k: &BigUint,
-    ) -> Result<(BigUint, BigUint), &'static str> {
-        if k >= self.curve.order() {
-            return Err("k must be less than the order of the curve");
+    ) -> Result<(BigUint, BigUint), Error> {
+        if k.is_zero() || k >= self.curve.order() {
+            return Err(Error::InvalidScalar);
         }
 
         let r = self.calculate_r(k);
-        let s = self.calculate_s(message, private_key, k, &r);
+        if r.is_zero() {
+            return Err(Error::ZeroSignatureComponent);
+        }
+        let s = self.calculate_s(message, private_key, k, &r)?;
+        if s.is_zero() {
+            return Err(Error::ZeroSignatureComponent);
+        }
 
         info!("Message signed successfully");
         Ok((r, s))
     }
 
     fn calculate_r(&self, k: &BigUint) -> BigUint {
-        match self.curve.mul(self.curve.base_point(), k) {
-            Point::Coordinates(x, _) => x,
+        match self.curve.mul_base(k) {
+            Point::Coordinates(x, _) => self.scalars.reduce(&x),
             Point::Identity => {
                 warn!("Unexpected point at infinity during signing");
                 panic!("Unexpected point at infinity");
@@ -103,14 +389,17 @@ impl<T: EllipticCurve> ECDSA<T> {
         private_key: &BigUint,
         k: &BigUint,
         r: &BigUint,
-    ) -> BigUint {
-        let s = FiniteField::mul(r, private_key, self.curve.order());
-        let s = FiniteField::add(message, &s, self.curve.order());
-        let k_inv = FiniteField::inv_mul(k, self.curve.order());
-        FiniteField::mul(&s, &k_inv, self.curve.order())
+    ) -> Result<BigUint, Error> {
+        let k_inv = self.scalars.try_inv(k)?;
+        let s = self.scalars.add(message, &self.scalars.mul(r, private_key));
+        Ok(self.scalars.mul(&s, &k_inv))
     }
 
     fn is_valid_signature(&self, r: &BigUint, s: &BigUint) -> bool {
+        if r.is_zero() || s.is_zero() {
+            warn!("Invalid signature: r or s is zero");
+            return false;
+        }
         if r >= self.curve.order() || s >= self.curve.order() {
             warn!("Invalid signature: r or s is too large");
             return false;
@@ -118,20 +407,37 @@ impl<T: EllipticCurve> ECDSA<T> {
         true
     }
 
+    /// Full public key validation: not the identity, coordinates reduced, on
+    /// the curve and in the prime-order subgroup
+    fn is_valid_public_key(&self, public_key: &Point) -> bool {
+        let valid = match public_key.coordinates() {
+            None => false,
+            Some((x, y)) => {
+                let p = self.curve.field_modulus();
+                x < p && y < p && self.curve.is_in_subgroup(public_key)
+            }
+        };
+        if !valid {
+            warn!("Invalid public key");
+        }
+        valid
+    }
+
     fn calculate_verification_point(
         &self,
         u1: &BigUint,
         u2: &BigUint,
         public_key: &Point,
     ) -> Point {
-        let u1a = self.curve.mul(self.curve.base_point(), u1);
-        let u2b = self.curve.mul(public_key, u2);
-        self.curve.add(&u1a, &u2b)
+        self.curve
+            .mul_double(self.curve.base_point(), u1, public_key, u2)
     }
 
     fn is_signature_valid(&self, point: Point, r: &BigUint) -> bool {
         match point {
-            Point::Coordinates(x, _) => x == *r,
+            Point::Coordinates(x, _) => {
+                FiniteField::ct_eq(&self.scalars.reduce(&x), r, self.curve.order())
+            }
             Point::Identity => {
                 warn!("Unexpected point at infinity during verification");
                 false
@@ -139,9 +445,9 @@ impl<T: EllipticCurve> ECDSA<T> {
         }
     }
 
-    fn generate_random_private_key(&self) -> BigUint {
+    fn generate_random_private_key<R: RngCore>(&self, rng: &mut R) -> BigUint {
         debug!("Generating random private key");
-        thread_rng().gen_biguint_range(&BigUint::from(1u32), self.curve.order())
+        rng.gen_biguint_range(&BigUint::from(1u32), self.curve.order())
     }
 
     fn validate_public_key(&self, public_key: &Point) {
@@ -152,13 +458,250 @@ impl<T: EllipticCurve> ECDSA<T> {
     }
 }
 
-#[cfg(test)]
-use crate::WeierstrassCurve;
+impl ECDSA<WeierstrassCurve> {
+    /// Exports a public key in SEC1 compressed form
+    pub fn export_public_key_compressed(&self, public_key: &Point) -> Vec<u8> {
+        self.curve.to_sec1_compressed(public_key)
+    }
+
+    /// Imports a SEC1 compressed public key, rejecting points outside the
+    /// prime-order subgroup
+    pub fn import_public_key_compressed(&self, bytes: &[u8]) -> Result<Point, Error> {
+        let public_key = self.curve.from_sec1_compressed(bytes)?;
+        if !self.curve.is_in_subgroup(&public_key) {
+            return Err(Error::NotInSubgroup);
+        }
+        Ok(public_key)
+    }
+
+    /// Serializes a keypair as the private scalar, padded to the byte length
+    /// of the order, followed by the SEC1 compressed public key
+    pub fn export_keypair(&self, private_key: &BigUint, public_key: &Point) -> Vec<u8> {
+        let mut bytes = to_bytes_be_padded(private_key, byte_length(self.curve.order()));
+        bytes.extend(self.export_public_key_compressed(public_key));
+        bytes
+    }
+
+    /// Loads a keypair written by `export_keypair`. The stored public key must
+    /// equal the one derived from the private key.
+    pub fn import_keypair(&self, bytes: &[u8]) -> Result<(PrivateKey, Point), Error> {
+        let len = byte_length(self.curve.order());
+        if bytes.len() <= len {
+            return Err(Error::InvalidEncoding);
+        }
+
+        let (private_key, derived) = self.import_private_key(&bytes[..len])?;
+        let public_key = self.import_public_key_compressed(&bytes[len..])?;
+        if public_key != derived {
+            return Err(Error::KeyMismatch);
+        }
+        Ok((private_key, public_key))
+    }
+
+    /// Recovers the public key from a signature and its recovery id.
+    ///
+    /// Bit 0 of `recovery_id` is the parity of `R.y` and bit 1 says whether
+    /// `R.x = r + n` rather than `r`. The key is `r⁻¹·(s·R - e·G)`.
+    pub fn recover_public_key(
+        &self,
+        message: &BigUint,
+        signature: &(BigUint, BigUint),
+        recovery_id: u8,
+    ) -> Result<Point, Error> {
+        let (r, s) = signature;
+        if recovery_id > 3 {
+            return Err(Error::InvalidEncoding);
+        }
+        if !self.is_valid_signature(r, s) {
+            return Err(Error::InvalidScalar);
+        }
+        let big_r = self.nonce_point(r, recovery_id)?;
+
+        // over a composite order r may have no inverse
+        let r_inv = self.scalars.try_inv(r)?;
+        let message = self.curve.reduce_scalar(message);
+        let u1 = self
+            .curve
+            .negate_scalar(&self.scalars.mul(&message, &r_inv));
+        let u2 = self.scalars.mul(s, &r_inv);
+        match self
+            .curve
+            .mul_double(self.curve.base_point(), &u1, &big_r, &u2)
+        {
+            // s·R = e·G: no key verifies this signature
+            Point::Identity => Err(Error::InvalidEncoding),
+            public_key => Ok(public_key),
+        }
+    }
+
+    /// Verifies a batch of `(message, signature, recovery_id, public_key)`
+    /// entries and returns `true` only if every signature is valid.
+    #[cfg(feature = "std")]
+    pub fn verify_batch_recoverable(
+        &self,
+        items: &[(BigUint, (BigUint, BigUint), u8, Point)],
+    ) -> bool {
+        self.verify_batch_recoverable_with_rng(items, &mut thread_rng())
+    }
+
+    /// Batch verification with a random linear combination.
+    ///
+    /// Each entry's nonce point `R` is lifted from `r` and its recovery id, and
+    /// a valid signature satisfies `R = u1·G + u2·Q` with `u1 = e/s` and
+    /// `u2 = r/s`. Weighting every equation with a random `z` in `[1, n)` and
+    /// summing gives one check, `Σz·R - (Σz·u1)·G - Σ(z·u2)·Q = O`, evaluated
+    /// with a single multi-scalar multiplication. An invalid entry makes the
+    /// sum vanish only with probability `1/n`.
+    pub fn verify_batch_recoverable_with_rng<R: RngCore>(
+        &self,
+        items: &[(BigUint, (BigUint, BigUint), u8, Point)],
+        rng: &mut R,
+    ) -> bool {
+        debug!(
+            "Verifying a batch of {} recoverable signatures",
+            items.len()
+        );
+        let order = self.curve.order();
+        let mut base_scalar = BigUint::zero();
+        let mut terms = Vec::with_capacity(2 * items.len() + 1);
+
+        for (message, (r, s), recovery_id, public_key) in items {
+            if !self.is_valid_public_key(public_key) || !self.is_valid_signature(r, s) {
+                return false;
+            }
+            let (Ok(big_r), Ok(s_inv)) =
+                (self.nonce_point(r, *recovery_id), self.scalars.try_inv(s))
+            else {
+                return false;
+            };
+
+            let z = rng.gen_biguint_range(&BigUint::from(1u32), order);
+            let z_w = self.scalars.mul(&z, &s_inv);
+            let message = self.curve.reduce_scalar(message);
+            base_scalar = self
+                .scalars
+                .add(&base_scalar, &self.scalars.mul(&z_w, &message));
+            terms.push((big_r, z));
+            terms.push((
+                public_key.clone(),
+                self.scalars.neg(&self.scalars.mul(&z_w, r)),
+            ));
+        }
+
+        terms.push((
+            self.curve.base_point().clone(),
+            self.scalars.neg(&base_scalar),
+        ));
+        self.curve.mul_sum(&terms) == Point::Identity
+    }
+
+    /// Lifts a signature's nonce point `R` from `r` and the recovery id: bit 0
+    /// is the parity of `R.y` and bit 1 says whether `R.x = r + n` rather
+    /// than `r`.
+    fn nonce_point(&self, r: &BigUint, recovery_id: u8) -> Result<Point, Error> {
+        if recovery_id > 3 {
+            return Err(Error::InvalidEncoding);
+        }
+        let x = if recovery_id & 2 == 0 {
+            r.clone()
+        } else {
+            r + self.curve.order()
+        };
+        if &x >= self.curve.field_modulus() {
+            return Err(Error::InvalidEncoding);
+        }
+        let mut encoded = vec![0x02 | (recovery_id & 1)];
+        encoded.extend(to_bytes_be_padded(
+            &x,
+            byte_length(self.curve.field_modulus()),
+        ));
+        self.import_public_key_compressed(&encoded)
+    }
+
+    /// Signs in the Ethereum `(v, r, s)` form with a deterministic RFC 6979
+    /// nonce. `s` is normalized to the lower half of the order and
+    /// `v = 27 + recovery_id`, so `recover_public_key(message, &(r, s), v - 27)`
+    /// returns the signer's key.
+    pub fn sign_eth(
+        &self,
+        message: &BigUint,
+        private_key: &BigUint,
+    ) -> Result<(u8, BigUint, BigUint), Error> {
+        let message = &self.normalize_input(message, private_key)?;
+        let order = self.curve.order();
+        let k = self.hedged_nonce(message, private_key, &[]);
+
+        let (x, y) = match self.curve.mul_base(&k) {
+            Point::Coordinates(x, y) => (x, y),
+            Point::Identity => return Err(Error::Identity),
+        };
+        let r = self.scalars.reduce(&x);
+        if r.is_zero() {
+            return Err(Error::ZeroSignatureComponent);
+        }
+        let mut s = self.calculate_s(message, private_key, &k, &r)?;
+        if s.is_zero() {
+            return Err(Error::ZeroSignatureComponent);
+        }
+
+        let mut recovery_id = u8::from(y.bit(0)) | if &x >= order { 2 } else { 0 };
+        // -s signs with -k, whose point R has the opposite y parity
+        if s > order >> 1 {
+            s = self.curve.negate_scalar(&s);
+            recovery_id ^= 1;
+        }
+        Ok((27 + recovery_id, r, s))
+    }
+
+    /// Recovers the public key for every recovery id that yields one. The
+    /// signer's key is among the candidates; callers match against the key
+    /// they expect.
+    pub fn recover_all_candidates(
+        &self,
+        message: &BigUint,
+        signature: &(BigUint, BigUint),
+    ) -> Vec<Point> {
+        (0..=3)
+            .filter_map(|recovery_id| {
+                self.recover_public_key(message, signature, recovery_id)
+                    .ok()
+            })
+            .collect()
+    }
+}
+
+/// Converts a hash to an integer, keeping only its leftmost `order_bits` bits
+/// when it is longer (`bits2int` from RFC 6979, section 2.3.2). This is how
+/// ECDSA uses e.g. a SHA-512 digest with a 256-bit order.
+pub fn bits2int(hash: &[u8], order_bits: usize) -> BigUint {
+    let hash_bits = 8 * hash.len();
+    BigUint::from_bytes_be(hash) >> hash_bits.saturating_sub(order_bits)
+}
+
+/// Verifies a signature given entirely as hex strings.
+///
+/// The curve is resolved by name, `pubkey_hex` is a SEC1 compressed public
+/// key, `message_hex` is the message hash as a big-endian integer and `sig_hex`
+/// is `r || s` with each component padded to the byte length of the order.
+pub fn quick_verify(
+    curve_name: &str,
+    pubkey_hex: &str,
+    message_hex: &str,
+    sig_hex: &str,
+) -> Result<bool, Error> {
+    let curve = by_name(curve_name).ok_or(Error::UnknownCurve)?;
+    let public_key = curve.from_sec1_compressed(&decode_hex(pubkey_hex)?)?;
+    let message = BigUint::from_bytes_be(&decode_hex(message_hex)?);
+
+    let ecdsa = ECDSA::new(curve);
+    let signature = ecdsa.signature_from_bytes(&decode_hex(sig_hex)?)?;
+
+    Ok(ecdsa.verify(&message, &signature, &public_key))
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use env_logger;
 
     fn init() {
         let _ = env_logger::builder().is_test(true).try_init();
@@ -175,12 +718,45 @@ mod tests {
         ECDSA::new(curve)
     }
 
+    #[test]
+    fn test_derive_private_key_from_seed() {
+        let ecdsa = ECDSA::new(crate::curves::secp256k1::create_secp256k1_weierstrass());
+        let key = ecdsa.derive_private_key_from_seed(b"correct horse battery staple");
+
+        assert_eq!(
+            key,
+            ecdsa.derive_private_key_from_seed(b"correct horse battery staple")
+        );
+        assert_ne!(
+            key,
+            ecdsa.derive_private_key_from_seed(b"correct horse battery stapler")
+        );
+        assert!(!key.is_zero() && &key < ecdsa.curve().order());
+
+        // on a 19-element group the 5-bit candidates 0 and 19..=31 are rejected
+        let toy = create_test_ecdsa();
+        for seed in 0u8..50 {
+            let key = toy.derive_private_key_from_seed(&[seed]);
+            assert!(!key.is_zero() && &key < toy.curve().order());
+            assert_eq!(key, toy.derive_private_key_from_seed(&[seed]));
+        }
+    }
+
+    #[test]
+    fn test_curve_accessor() {
+        let ecdsa = create_test_ecdsa();
+        assert_eq!(ecdsa.curve().order(), &BigUint::from(19u32));
+
+        let (_, public_key) = ecdsa.generate_keypair();
+        assert!(ecdsa.curve().is_on_curve(&public_key));
+    }
+
     #[test]
     fn test_generate_keypair() {
         init();
         let ecdsa = create_test_ecdsa();
         let (private_key, public_key) = ecdsa.generate_keypair();
-        assert!(private_key < *ecdsa.curve.order());
+        assert!(*private_key < *ecdsa.curve.order());
         assert!(
             ecdsa.curve.is_on_curve(&public_key),
             "Generated public key is not on the curve"
@@ -202,8 +778,100 @@ mod tests {
         assert!(ecdsa.verify(&message, &signature, &public_key));
     }
 
+    #[test]
+    fn test_message_above_order_is_reduced() {
+        use crate::curves::secp256k1::create_secp256k1_weierstrass;
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let ecdsa = ECDSA::new(create_secp256k1_weierstrass());
+        let private_key = BigUint::from(0xC0FFEEu32);
+        let public_key = ecdsa.generate_public_key(&private_key);
+        let message = BigUint::from(123456789u32);
+        let oversized = &message + ecdsa.curve.order();
+
+        let signature = ecdsa
+            .sign_with_rng(&oversized, &private_key, &mut StdRng::seed_from_u64(3))
+            .unwrap();
+        assert!(ecdsa.verify(&oversized, &signature, &public_key));
+        assert!(ecdsa.verify(&message, &signature, &public_key));
+        assert_eq!(
+            ecdsa.sign_with_rng(&message, &private_key, &mut StdRng::seed_from_u64(3)),
+            Ok(signature)
+        );
+    }
+
+    #[test]
+    fn test_r_is_reduced_modulo_order() {
+        // y^2 = x^3 + 2x + 5 over F_23 with G = (1, 10) of order 11: 5·G = (15, 12)
+        // has x above the order, so r = 15 mod 11 = 4
+        let curve = WeierstrassCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(5u32),
+            BigUint::from(23u32),
+            BigUint::from(11u32),
+            Point::Coordinates(BigUint::from(1u32), BigUint::from(10u32)),
+        )
+        .with_cofactor(BigUint::from(3u32));
+        let ecdsa = ECDSA::new(curve);
+        let private_key = BigUint::from(3u32);
+        let public_key = ecdsa.generate_public_key(&private_key);
+        let message = BigUint::from(7u32);
+
+        let signature = ecdsa
+            .sign_with_nonce(&message, &private_key, &BigUint::from(5u32))
+            .unwrap();
+        assert_eq!(signature.0, BigUint::from(4u32));
+        assert!(ecdsa.verify(&message, &signature, &public_key));
+    }
+
+    #[test]
+    fn test_verify_rejects_invalid_public_keys() {
+        let ecdsa = create_test_ecdsa();
+        let private_key = BigUint::from(7u32);
+        let public_key = ecdsa.generate_public_key(&private_key);
+        let message = BigUint::from(10u32);
+        let signature = ecdsa
+            .sign_with_k(&message, &private_key, &BigUint::from(18u32))
+            .unwrap();
+        assert!(ecdsa.verify(&message, &signature, &public_key));
+
+        assert!(!ecdsa.verify(&message, &signature, &Point::Identity));
+        let off_curve = Point::Coordinates(BigUint::from(4u32), BigUint::from(2u32));
+        assert!(!ecdsa.verify(&message, &signature, &off_curve));
+        if let Point::Coordinates(x, y) = &public_key {
+            let unreduced = Point::Coordinates(x + 17u32, y.clone());
+            assert!(!ecdsa.verify(&message, &signature, &unreduced));
+        }
+
+        // (4, 10) has order 3 on y^2 = x^3 + 2x + 5 over F_23, whose subgroup has order 11
+        let curve = WeierstrassCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(5u32),
+            BigUint::from(23u32),
+            BigUint::from(11u32),
+            Point::Coordinates(BigUint::from(1u32), BigUint::from(10u32)),
+        )
+        .with_cofactor(BigUint::from(3u32));
+        let ecdsa = ECDSA::new(curve);
+        let small_order = Point::Coordinates(BigUint::from(4u32), BigUint::from(10u32));
+        // (5, 8) satisfies the verification equation for this key and message 1
+        let signature = (BigUint::from(5u32), BigUint::from(8u32));
+        let s_inv = FiniteField::inv_mul(&signature.1, ecdsa.curve.order());
+        let point = ecdsa.curve.mul_double(
+            ecdsa.curve.base_point(),
+            &s_inv,
+            &small_order,
+            &FiniteField::mul(&signature.0, &s_inv, ecdsa.curve.order()),
+        );
+        assert!(ecdsa.is_signature_valid(point, &signature.0));
+        assert!(!ecdsa.verify(&BigUint::from(1u32), &signature, &small_order));
+    }
+
     #[test]
     fn test_verify_invalid_signature() {
+        use rand::SeedableRng;
+
         init();
         let ecdsa = create_test_ecdsa();
         let (private_key, public_key) = (
@@ -211,8 +879,985 @@ mod tests {
             ecdsa.generate_public_key(&BigUint::from(7u32)),
         );
         let message = BigUint::from(5u32);
-        let mut signature = ecdsa.sign(&message, &private_key).unwrap();
+        // `sign` with a seeded rng: on a 19-element group one random nonce in
+        // 18 gives s = 9, where the tampered s + 1 still verifies
+        let mut rng = rand::rngs::StdRng::seed_from_u64(309);
+        let mut signature = ecdsa
+            .sign_with_rng(&message, &private_key, &mut rng)
+            .unwrap();
         signature.1 += BigUint::from(1u32); // Modify the signature to make it invalid
         assert!(!ecdsa.verify(&message, &signature, &public_key));
     }
+
+    #[test]
+    fn test_verify_rejects_tampered_s_with_fixed_nonce() {
+        init();
+        let ecdsa = create_test_ecdsa();
+        let private_key = BigUint::from(7u32);
+        let public_key = ecdsa.generate_public_key(&private_key);
+        let message = BigUint::from(5u32);
+        // on a 19-element group s + 1 still verifies when s / (s + 1) = -1,
+        // i.e. s = 9, so a fixed nonce pins down a signature where it does not
+        let mut signature = ecdsa
+            .sign_with_k(&message, &private_key, &BigUint::from(18u32))
+            .unwrap();
+        assert_ne!(signature.1, BigUint::from(9u32));
+        signature.1 += BigUint::from(1u32);
+        assert!(!ecdsa.verify(&message, &signature, &public_key));
+    }
+
+    #[test]
+    fn test_verify_results_across_valid_and_invalid_signatures() {
+        init();
+        let ecdsa = ECDSA::new(crate::curves::secp256k1::create_secp256k1_weierstrass());
+        let n = ecdsa.curve().order().clone();
+        let private_key = BigUint::from(0xC0FFEEu32);
+        let public_key = ecdsa.generate_public_key(&private_key);
+        let message = BigUint::from(12345u32);
+        let (r, s) = ecdsa
+            .sign_with_k(&message, &private_key, &BigUint::from(0xBEEFu32))
+            .unwrap();
+        let one = BigUint::from(1u32);
+
+        assert!(ecdsa.verify(&message, &(r.clone(), s.clone()), &public_key));
+        // (r, -s) is the same signature with R negated
+        assert!(ecdsa.verify(&message, &(r.clone(), &n - &s), &public_key));
+
+        let other_key = ecdsa.generate_public_key(&(&private_key + 1u32));
+        assert!(!ecdsa.verify(&message, &(r.clone(), s.clone()), &other_key));
+        assert!(!ecdsa.verify(&(&message + 1u32), &(r.clone(), s.clone()), &public_key));
+        for signature in [
+            (&r + 1u32, s.clone()),
+            (&r - 1u32, s.clone()),
+            (r.clone(), &s + 1u32),
+            (r.clone() ^ (&one << 255), s.clone()),
+            (s.clone(), r.clone()),
+            (r.clone(), n.clone()),
+            (&r + &n, s.clone()),
+        ] {
+            assert!(!ecdsa.verify(&message, &signature, &public_key));
+        }
+    }
+
+    #[test]
+    fn test_seeded_rng_is_deterministic() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        init();
+        let ecdsa = create_test_ecdsa();
+        let (key1, public1) = ecdsa.generate_keypair_with_rng(&mut StdRng::seed_from_u64(42));
+        let (key2, public2) = ecdsa.generate_keypair_with_rng(&mut StdRng::seed_from_u64(42));
+        assert_eq!(key1, key2);
+        assert_eq!(public1, public2);
+
+        let message = BigUint::from(10u32);
+        let sig1 = ecdsa
+            .sign_with_rng(&message, &key1, &mut StdRng::seed_from_u64(7))
+            .unwrap();
+        let sig2 = ecdsa
+            .sign_with_rng(&message, &key1, &mut StdRng::seed_from_u64(7))
+            .unwrap();
+        assert_eq!(sig1, sig2);
+        assert!(ecdsa.verify(&message, &sig1, &public1));
+    }
+
+    #[test]
+    fn test_verify_rejects_zero_components() {
+        init();
+        let ecdsa = create_test_ecdsa();
+        let public_key = ecdsa.generate_public_key(&BigUint::from(7u32));
+        let message = BigUint::from(10u32);
+
+        let zero_s = (BigUint::from(3u32), BigUint::zero());
+        assert!(!ecdsa.verify(&message, &zero_s, &public_key));
+        let zero_r = (BigUint::zero(), BigUint::from(3u32));
+        assert!(!ecdsa.verify(&message, &zero_r, &public_key));
+    }
+
+    mod import_private_key {
+        use super::*;
+        use crate::curves::secp256k1::create_secp256k1_weierstrass;
+
+        #[test]
+        fn test_import_known_key() {
+            let ecdsa = ECDSA::new(create_secp256k1_weierstrass());
+            let bytes =
+                decode_hex("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721")
+                    .unwrap();
+            let expected = Point::Coordinates(
+                BigUint::parse_bytes(
+                    b"2c8c31fc9f990c6b55e3865a184a4ce50e09481f2eaeb3e60ec1cea13a6ae645",
+                    16,
+                )
+                .unwrap(),
+                BigUint::parse_bytes(
+                    b"64b95e4fdb6948c0386e189b006a29f686769b011704275e4459822dc3328085",
+                    16,
+                )
+                .unwrap(),
+            );
+
+            let (private_key, public_key) = ecdsa.import_private_key(&bytes).unwrap();
+            assert_eq!(*private_key, BigUint::from_bytes_be(&bytes));
+            assert_eq!(public_key, expected);
+        }
+
+        #[test]
+        fn test_rejects_out_of_range_keys() {
+            let ecdsa = ECDSA::new(create_secp256k1_weierstrass());
+            let order = ecdsa.curve.order().to_bytes_be();
+
+            assert_eq!(ecdsa.import_private_key(&[]), Err(Error::InvalidScalar));
+            assert_eq!(
+                ecdsa.import_private_key(&[0u8; 32]),
+                Err(Error::InvalidScalar)
+            );
+            assert_eq!(ecdsa.import_private_key(&order), Err(Error::InvalidScalar));
+            assert_eq!(
+                ecdsa.import_private_key(&[0xffu8; 32]),
+                Err(Error::InvalidScalar)
+            );
+            assert!(ecdsa.import_private_key(&[1]).is_ok());
+        }
+    }
+
+    mod public_key_compression {
+        use super::*;
+        use crate::curves::secp256k1::create_secp256k1_weierstrass;
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        #[test]
+        fn test_round_trip_generated_keypair() {
+            let ecdsa = ECDSA::new(create_secp256k1_weierstrass());
+            let (_, public_key) = ecdsa.generate_keypair_with_rng(&mut StdRng::seed_from_u64(9));
+
+            let bytes = ecdsa.export_public_key_compressed(&public_key);
+            assert_eq!(bytes.len(), 33);
+            assert_eq!(ecdsa.import_public_key_compressed(&bytes), Ok(public_key));
+        }
+
+        #[test]
+        fn test_rejects_points_outside_subgroup() {
+            // y^2 = x^3 + 2x + 5 over F_23, subgroup of order 11 and cofactor 3
+            let curve = WeierstrassCurve::new(
+                BigUint::from(2u32),
+                BigUint::from(5u32),
+                BigUint::from(23u32),
+                BigUint::from(11u32),
+                Point::Coordinates(BigUint::from(1u32), BigUint::from(10u32)),
+            )
+            .with_cofactor(BigUint::from(3u32));
+            let ecdsa = ECDSA::new(curve);
+
+            // (1, 10) generates the subgroup, (4, 10) has order 3
+            assert!(ecdsa.import_public_key_compressed(&[0x02, 1]).is_ok());
+            assert_eq!(
+                ecdsa.import_public_key_compressed(&[0x02, 4]),
+                Err(Error::NotInSubgroup)
+            );
+            // x = 0 gives x^3 + 2x + 5 = 5, a non-residue mod 23
+            assert_eq!(
+                ecdsa.import_public_key_compressed(&[0x02, 0]),
+                Err(Error::NotOnCurve)
+            );
+            assert_eq!(
+                ecdsa.import_public_key_compressed(&[0x04, 1]),
+                Err(Error::InvalidEncoding)
+            );
+        }
+    }
+
+    mod keypair_bytes {
+        use super::*;
+        use crate::curves::secp256k1::create_secp256k1_weierstrass;
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        #[test]
+        fn test_round_trip() {
+            let ecdsa = ECDSA::new(create_secp256k1_weierstrass());
+            let (private_key, public_key) =
+                ecdsa.generate_keypair_with_rng(&mut StdRng::seed_from_u64(21));
+
+            let bytes = ecdsa.export_keypair(&private_key, &public_key);
+            assert_eq!(bytes.len(), 32 + 33);
+
+            let (imported_private, imported_public) = ecdsa.import_keypair(&bytes).unwrap();
+            assert_eq!(*imported_private, *private_key);
+            assert_eq!(imported_public, public_key);
+        }
+
+        #[test]
+        fn test_rejects_mismatched_keys() {
+            let ecdsa = ECDSA::new(create_secp256k1_weierstrass());
+            let mut rng = StdRng::seed_from_u64(22);
+            let (private_key, _) = ecdsa.generate_keypair_with_rng(&mut rng);
+            let (_, other_public) = ecdsa.generate_keypair_with_rng(&mut rng);
+
+            let bytes = ecdsa.export_keypair(&private_key, &other_public);
+            assert_eq!(ecdsa.import_keypair(&bytes).err(), Some(Error::KeyMismatch));
+        }
+
+        #[test]
+        fn test_rejects_malformed_input() {
+            let ecdsa = ECDSA::new(create_secp256k1_weierstrass());
+            let (private_key, public_key) =
+                ecdsa.generate_keypair_with_rng(&mut StdRng::seed_from_u64(23));
+            let bytes = ecdsa.export_keypair(&private_key, &public_key);
+
+            assert_eq!(
+                ecdsa.import_keypair(&bytes[..32]).err(),
+                Some(Error::InvalidEncoding)
+            );
+            assert_eq!(
+                ecdsa.import_keypair(&bytes[..64]).err(),
+                Some(Error::InvalidEncoding)
+            );
+
+            let mut zero_key = bytes.clone();
+            zero_key[..32].fill(0);
+            assert_eq!(
+                ecdsa.import_keypair(&zero_key).err(),
+                Some(Error::InvalidScalar)
+            );
+        }
+    }
+
+    mod key_recovery {
+        use super::*;
+        use crate::curves::secp256k1::create_secp256k1_weierstrass;
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        #[test]
+        fn test_public_key_is_among_candidates() {
+            let ecdsa = ECDSA::new(create_secp256k1_weierstrass());
+            let mut rng = StdRng::seed_from_u64(66);
+
+            for i in 0..4u32 {
+                let (private_key, public_key) = ecdsa.generate_keypair_with_rng(&mut rng);
+                let message = BigUint::from(1000u32 + i);
+                let signature = ecdsa
+                    .sign_with_rng(&message, &private_key, &mut rng)
+                    .unwrap();
+
+                let candidates = ecdsa.recover_all_candidates(&message, &signature);
+                assert!(candidates.contains(&public_key));
+                for candidate in &candidates {
+                    assert!(ecdsa.curve.is_on_curve(candidate));
+                }
+            }
+        }
+
+        #[test]
+        fn test_rejects_r_without_inverse() {
+            // the 33-point curve y^2 = x^3 + 2x + 5 over F_23 with n = 33: R
+            // lifts from x = 11, but 11 has no inverse modulo 33
+            let curve = WeierstrassCurve::new(
+                BigUint::from(2u32),
+                BigUint::from(5u32),
+                BigUint::from(23u32),
+                BigUint::from(33u32),
+                Point::Coordinates(BigUint::from(5u32), BigUint::from(5u32)),
+            );
+            let ecdsa = ECDSA::new(curve);
+            let signature = (BigUint::from(11u32), BigUint::from(1u32));
+
+            for recovery_id in 0..=1 {
+                assert_eq!(
+                    ecdsa.recover_public_key(&BigUint::from(10u32), &signature, recovery_id),
+                    Err(Error::NoInverse)
+                );
+            }
+            assert!(ecdsa
+                .recover_all_candidates(&BigUint::from(10u32), &signature)
+                .is_empty());
+        }
+
+        #[test]
+        fn test_verify_batch() {
+            let ecdsa = ECDSA::new(create_secp256k1_weierstrass());
+            let mut rng = StdRng::seed_from_u64(292);
+            let mut items = Vec::new();
+            for message in 1u32..=4 {
+                let (private_key, public_key) = ecdsa.generate_keypair_with_rng(&mut rng);
+                let message = BigUint::from(message);
+                let signature = ecdsa
+                    .sign_with_rng(&message, &private_key, &mut rng)
+                    .unwrap();
+                items.push((message, signature, public_key));
+            }
+            assert!(ecdsa.verify_batch(&items));
+            assert!(ecdsa.verify_batch(&[]));
+
+            let mut one_bad = items.clone();
+            one_bad[2].0 = BigUint::from(5u32);
+            assert!(!ecdsa.verify_batch(&one_bad));
+
+            let mut wrong_key = items.clone();
+            wrong_key[3].2 = items[0].2.clone();
+            assert!(!ecdsa.verify_batch(&wrong_key));
+        }
+
+        #[test]
+        fn test_verify_batch_recoverable() {
+            let ecdsa = ECDSA::new(create_secp256k1_weierstrass());
+            let mut rng = StdRng::seed_from_u64(29);
+            let mut items = Vec::new();
+            for (key, message) in [(3u32, 4u32), (7, 10), (11, 2)] {
+                let private_key = BigUint::from(key);
+                let message = BigUint::from(message);
+                let (v, r, s) = ecdsa.sign_eth(&message, &private_key).unwrap();
+                let public_key = ecdsa.generate_public_key(&private_key);
+                items.push((message, (r, s), v - 27, public_key));
+            }
+            assert!(ecdsa.verify_batch_recoverable_with_rng(&items, &mut rng));
+            assert!(ecdsa.verify_batch_recoverable_with_rng(&[], &mut rng));
+
+            let mut wrong_message = items.clone();
+            wrong_message[1].0 = BigUint::from(5u32);
+            assert!(!ecdsa.verify_batch_recoverable_with_rng(&wrong_message, &mut rng));
+
+            let mut wrong_parity = items.clone();
+            wrong_parity[2].2 ^= 1;
+            assert!(!ecdsa.verify_batch_recoverable_with_rng(&wrong_parity, &mut rng));
+
+            let mut wrong_key = items;
+            wrong_key[0].3 = ecdsa.generate_public_key(&BigUint::from(4u32));
+            assert!(!ecdsa.verify_batch_recoverable_with_rng(&wrong_key, &mut rng));
+        }
+
+        #[test]
+        fn test_sign_eth_recovers_with_v() {
+            let ecdsa = ECDSA::new(create_secp256k1_weierstrass());
+            let half_order = ecdsa.curve.order() >> 1;
+            let mut rng = StdRng::seed_from_u64(81);
+
+            for i in 0..6u32 {
+                let (private_key, public_key) = ecdsa.generate_keypair_with_rng(&mut rng);
+                let message = BigUint::from(0xE7E7u32 + i);
+
+                let (v, r, s) = ecdsa.sign_eth(&message, &private_key).unwrap();
+                assert!(v == 27 || v == 28);
+                assert!(s <= half_order);
+                assert_eq!(
+                    ecdsa.sign_eth(&message, &private_key),
+                    Ok((v, r.clone(), s.clone()))
+                );
+
+                let signature = (r, s);
+                assert!(ecdsa.verify(&message, &signature, &public_key));
+                assert_eq!(
+                    ecdsa.recover_public_key(&message, &signature, v - 27),
+                    Ok(public_key)
+                );
+            }
+        }
+
+        #[test]
+        fn test_recovery_id_from_nonce() {
+            let ecdsa = ECDSA::new(create_secp256k1_weierstrass());
+            let (private_key, public_key) =
+                ecdsa.generate_keypair_with_rng(&mut StdRng::seed_from_u64(67));
+            let message = BigUint::from(12345u32);
+            let k = BigUint::from(0xDEADBEEFu32);
+
+            let signature = ecdsa.sign_with_nonce(&message, &private_key, &k).unwrap();
+            let nonce_point = ecdsa.curve.mul_base(&k);
+            let recovery_id = nonce_point.coordinates().unwrap().1.bit(0) as u8;
+
+            assert_eq!(
+                ecdsa.recover_public_key(&message, &signature, recovery_id),
+                Ok(public_key.clone())
+            );
+            assert_ne!(
+                ecdsa.recover_public_key(&message, &signature, recovery_id ^ 1),
+                Ok(public_key)
+            );
+        }
+
+        #[test]
+        fn test_rejects_invalid_input() {
+            let ecdsa = ECDSA::new(create_secp256k1_weierstrass());
+            let message = BigUint::from(1u32);
+            let one = BigUint::from(1u32);
+
+            assert_eq!(
+                ecdsa.recover_public_key(&message, &(BigUint::zero(), one.clone()), 0),
+                Err(Error::InvalidScalar)
+            );
+            assert_eq!(
+                ecdsa.recover_public_key(&message, &(one.clone(), one.clone()), 4),
+                Err(Error::InvalidEncoding)
+            );
+            // r + n >= p, so R.x cannot be r + n
+            let r = ecdsa.curve.field_modulus() - ecdsa.curve.order();
+            assert_eq!(
+                ecdsa.recover_public_key(&message, &(r, one), 2),
+                Err(Error::InvalidEncoding)
+            );
+        }
+    }
+
+    mod signature_bytes {
+        use super::*;
+        use crate::curves::secp256k1::create_secp256k1_weierstrass;
+
+        #[test]
+        fn test_round_trip_on_secp256k1() {
+            let ecdsa = ECDSA::new(create_secp256k1_weierstrass());
+            let private_key = BigUint::from(0xC0FFEEu32);
+            let message = BigUint::from(42u32);
+
+            // the second signature has short components, exercising the padding
+            for signature in [
+                ecdsa
+                    .sign_with_k(&message, &private_key, &BigUint::from(12345u32))
+                    .unwrap(),
+                (BigUint::from(1u32), BigUint::from(0x0100u32)),
+            ] {
+                let bytes = ecdsa.signature_to_bytes(&signature);
+                assert_eq!(bytes.len(), 64);
+                assert_eq!(ecdsa.signature_from_bytes(&bytes), Ok(signature));
+            }
+        }
+
+        #[test]
+        fn test_rejects_malformed_signatures() {
+            let ecdsa = ECDSA::new(create_secp256k1_weierstrass());
+            let bytes = ecdsa.signature_to_bytes(&(BigUint::from(1u32), BigUint::from(2u32)));
+
+            assert_eq!(
+                ecdsa.signature_from_bytes(&bytes[..63]),
+                Err(Error::InvalidEncoding)
+            );
+            assert_eq!(
+                ecdsa.signature_from_bytes(&[bytes.as_slice(), &[0]].concat()),
+                Err(Error::InvalidEncoding)
+            );
+
+            let too_large =
+                ecdsa.signature_to_bytes(&(ecdsa.curve.order().clone(), BigUint::from(2u32)));
+            assert_eq!(
+                ecdsa.signature_from_bytes(&too_large),
+                Err(Error::InvalidEncoding)
+            );
+        }
+    }
+
+    mod nonce_signing {
+        use super::*;
+        use crate::curves::secp256k1::create_secp256k1_weierstrass;
+
+        #[test]
+        fn test_known_vector() {
+            // RFC 6979 nonce for key 1 and SHA-256("Satoshi Nakamoto"); the
+            // published s is the low-s form, order - s
+            let ecdsa = ECDSA::new(create_secp256k1_weierstrass());
+            let message = ecdsa.hash_message(b"Satoshi Nakamoto");
+            let private_key = BigUint::from(1u32);
+            let k = BigUint::parse_bytes(
+                b"8F8A276C19F4149656B280621E358CCE24F5F52542772691EE69063B74F15D15",
+                16,
+            )
+            .unwrap();
+            let r = BigUint::parse_bytes(
+                b"934B1EA10A4B3C1757E2B0C017D0B6143CE3C9A7E6A4A49860D7A6AB210EE3D8",
+                16,
+            )
+            .unwrap();
+            let low_s = BigUint::parse_bytes(
+                b"2442CE9D2B916064108014783E923EC36B49743E2FFA1C4496F01A512AAFD9E5",
+                16,
+            )
+            .unwrap();
+
+            let signature = ecdsa.sign_with_nonce(&message, &private_key, &k).unwrap();
+            assert_eq!(signature, (r, ecdsa.curve.order() - low_s));
+            assert!(ecdsa.verify(
+                &message,
+                &signature,
+                &ecdsa.generate_public_key(&private_key)
+            ));
+        }
+
+        #[test]
+        fn test_rejects_out_of_range_nonce() {
+            let ecdsa = create_test_ecdsa();
+            let (message, private_key) = (BigUint::from(10u32), BigUint::from(7u32));
+
+            assert_eq!(
+                ecdsa.sign_with_nonce(&message, &private_key, &BigUint::from(0u32)),
+                Err(Error::InvalidScalar)
+            );
+            assert_eq!(
+                ecdsa.sign_with_nonce(&message, &private_key, ecdsa.curve.order()),
+                Err(Error::InvalidScalar)
+            );
+            assert!(ecdsa
+                .sign_with_nonce(&message, &private_key, &BigUint::from(18u32))
+                .is_ok());
+        }
+
+        #[test]
+        fn test_rejects_nonce_without_inverse() {
+            // y^2 = x^3 + 2x + 5 over F_23 has 33 points and (5, 5) generates
+            // all of them, so the order is composite and k = 3 has no inverse
+            let curve = WeierstrassCurve::new(
+                BigUint::from(2u32),
+                BigUint::from(5u32),
+                BigUint::from(23u32),
+                BigUint::from(33u32),
+                Point::Coordinates(BigUint::from(5u32), BigUint::from(5u32)),
+            );
+            let ecdsa = ECDSA::new(curve);
+            let (message, private_key) = (BigUint::from(10u32), BigUint::from(7u32));
+
+            assert_eq!(
+                ecdsa.sign_with_nonce(&message, &private_key, &BigUint::from(3u32)),
+                Err(Error::NoInverse)
+            );
+            assert!(ecdsa
+                .sign_with_nonce(&message, &private_key, &BigUint::from(4u32))
+                .is_ok());
+        }
+
+        #[test]
+        fn test_verifies_over_composite_order() {
+            // the 33-point curve from above: Fermat's little theorem gives no
+            // inverses modulo 33, so verification needs the extended Euclidean one
+            let curve = WeierstrassCurve::new(
+                BigUint::from(2u32),
+                BigUint::from(5u32),
+                BigUint::from(23u32),
+                BigUint::from(33u32),
+                Point::Coordinates(BigUint::from(5u32), BigUint::from(5u32)),
+            );
+            let ecdsa = ECDSA::new(curve);
+            let (message, private_key) = (BigUint::from(10u32), BigUint::from(7u32));
+            let public_key = ecdsa.generate_public_key(&private_key);
+
+            let mut verified = 0;
+            for k in (1u32..33).filter(|k| k % 3 != 0 && k % 11 != 0) {
+                let Ok(signature) =
+                    ecdsa.sign_with_nonce(&message, &private_key, &BigUint::from(k))
+                else {
+                    continue;
+                };
+                let invertible = ecdsa.scalars.try_inv(&signature.1).is_ok();
+                assert_eq!(
+                    ecdsa.verify(&message, &signature, &public_key),
+                    invertible,
+                    "k = {}",
+                    k
+                );
+                verified += usize::from(invertible);
+            }
+            assert!(verified > 0);
+        }
+
+        #[test]
+        fn test_rejects_zero_s() {
+            // s = (m + r·d) / k is zero when m = -r·d mod n
+            let ecdsa = create_test_ecdsa();
+            let (private_key, k) = (BigUint::from(7u32), BigUint::from(18u32));
+            let r = ecdsa
+                .sign_with_nonce(&BigUint::from(1u32), &private_key, &k)
+                .unwrap()
+                .0;
+            let order = ecdsa.curve.order();
+            let message = FiniteField::inv_add(&FiniteField::mul(&r, &private_key, order), order);
+
+            assert_eq!(
+                ecdsa.sign_with_nonce(&message, &private_key, &k),
+                Err(Error::ZeroSignatureComponent)
+            );
+        }
+    }
+
+    mod hedged_signing {
+        use super::*;
+        use crate::curves::secp256k1::create_secp256k1_weierstrass;
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        #[test]
+        fn test_different_randomness_gives_different_valid_signatures() {
+            let ecdsa = ECDSA::new(create_secp256k1_weierstrass());
+            let private_key = BigUint::from(0xC0FFEEu32);
+            let public_key = ecdsa.generate_public_key(&private_key);
+            let message = BigUint::from(123456789u32);
+
+            let sig1 = ecdsa
+                .sign_hedged(&message, &private_key, &mut StdRng::seed_from_u64(1))
+                .unwrap();
+            let sig2 = ecdsa
+                .sign_hedged(&message, &private_key, &mut StdRng::seed_from_u64(2))
+                .unwrap();
+
+            assert_ne!(sig1, sig2);
+            assert!(ecdsa.verify(&message, &sig1, &public_key));
+            assert!(ecdsa.verify(&message, &sig2, &public_key));
+        }
+
+        #[test]
+        fn test_same_inputs_give_same_signature() {
+            let ecdsa = ECDSA::new(create_secp256k1_weierstrass());
+            let private_key = BigUint::from(0xC0FFEEu32);
+            let message = BigUint::from(123456789u32);
+
+            assert_eq!(
+                ecdsa.sign_hedged(&message, &private_key, &mut StdRng::seed_from_u64(1)),
+                ecdsa.sign_hedged(&message, &private_key, &mut StdRng::seed_from_u64(1))
+            );
+        }
+
+        #[test]
+        fn test_empty_extra_data_matches_rfc6979() {
+            // widely used secp256k1 RFC 6979 vector: key 1, SHA-256("Satoshi Nakamoto")
+            let ecdsa = ECDSA::new(create_secp256k1_weierstrass());
+            let message = ecdsa.hash_message(b"Satoshi Nakamoto");
+            let expected = BigUint::parse_bytes(
+                b"8F8A276C19F4149656B280621E358CCE24F5F52542772691EE69063B74F15D15",
+                16,
+            )
+            .unwrap();
+
+            assert_eq!(
+                ecdsa.hedged_nonce(&message, &BigUint::from(1u32), &[]),
+                expected
+            );
+        }
+
+        #[test]
+        fn test_nonce_stays_in_range_on_small_order() {
+            // the 5-bit order rejects most candidates, exercising the retry loop
+            let ecdsa = create_test_ecdsa();
+            for extra in 0u8..50 {
+                let k = ecdsa.hedged_nonce(&BigUint::from(3u32), &BigUint::from(7u32), &[extra]);
+                assert!(!k.is_zero() && &k < ecdsa.curve.order());
+            }
+        }
+    }
+
+    mod message_signing {
+        use super::*;
+        use crate::curves::secp256k1::create_secp256k1_weierstrass;
+
+        #[test]
+        fn test_sign_and_verify_message() {
+            init();
+            let ecdsa = ECDSA::new(create_secp256k1_weierstrass());
+            let (private_key, public_key) = ecdsa.generate_keypair();
+
+            let signature = ecdsa.sign_message(b"hello world", &private_key).unwrap();
+            assert!(ecdsa.verify_message(b"hello world", &signature, &public_key));
+            assert!(!ecdsa.verify_message(b"hello world!", &signature, &public_key));
+        }
+
+        #[test]
+        fn test_verify_message_known_vector() {
+            // the quick_verify vector signs SHA-256("sample")
+            let curve = create_secp256k1_weierstrass();
+            let public_key = curve
+                .from_sec1_compressed(&decode_hex(quick_verify::PUBKEY).unwrap())
+                .unwrap();
+            let ecdsa = ECDSA::new(curve);
+            let signature = ecdsa
+                .signature_from_bytes(&decode_hex(quick_verify::SIGNATURE).unwrap())
+                .unwrap();
+
+            assert!(ecdsa.verify_message(b"sample", &signature, &public_key));
+        }
+
+        #[test]
+        fn test_sign_reader_matches_sign_message() {
+            use rand::rngs::StdRng;
+            use rand::SeedableRng;
+            use std::io::Cursor;
+
+            let ecdsa = ECDSA::new(create_secp256k1_weierstrass());
+            let (private_key, public_key) =
+                ecdsa.generate_keypair_with_rng(&mut StdRng::seed_from_u64(82));
+            // 3 MiB, not a multiple of the read buffer
+            let message: Vec<u8> = (0..3 * 1024 * 1024 + 5).map(|i| (i % 251) as u8).collect();
+
+            let streamed = ecdsa
+                .sign_reader_with_rng(
+                    Cursor::new(&message),
+                    &private_key,
+                    &mut StdRng::seed_from_u64(1),
+                )
+                .unwrap();
+            let one_shot = ecdsa
+                .sign_message_with_rng(&message, &private_key, &mut StdRng::seed_from_u64(1))
+                .unwrap();
+
+            assert_eq!(streamed, one_shot);
+            assert!(ecdsa.verify_message(&message, &streamed, &public_key));
+        }
+
+        #[test]
+        fn test_sign_reader_reports_read_errors() {
+            struct FailingReader;
+            impl Read for FailingReader {
+                fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+                    Err(std::io::ErrorKind::BrokenPipe.into())
+                }
+            }
+
+            let ecdsa = create_test_ecdsa();
+            assert_eq!(
+                ecdsa.sign_reader(FailingReader, &BigUint::from(7u32)),
+                Err(Error::ReadFailed)
+            );
+        }
+
+        #[test]
+        fn test_hash_is_truncated_to_order_bits() {
+            // the toy curve's 5-bit order keeps only the top 5 bits of the digest
+            let ecdsa = create_test_ecdsa();
+            let digest = Sha256::digest(b"sample");
+            assert_eq!(
+                ecdsa.hash_message(b"sample"),
+                BigUint::from(digest[0] >> 3) % 19u32
+            );
+        }
+    }
+
+    mod bits2int {
+        use super::*;
+
+        #[test]
+        fn test_keeps_leftmost_bits() {
+            let hash: Vec<u8> = (1..=64).collect();
+
+            assert_eq!(bits2int(&hash, 256), BigUint::from_bytes_be(&hash[..32]));
+            assert_eq!(bits2int(&hash, 512), BigUint::from_bytes_be(&hash));
+            // a longer order keeps the whole hash
+            assert_eq!(
+                bits2int(&hash[..32], 521),
+                BigUint::from_bytes_be(&hash[..32])
+            );
+            // 0x01 0x02 -> top 12 bits 0x010
+            assert_eq!(bits2int(&hash[..2], 12), BigUint::from(0x010u32));
+        }
+    }
+
+    mod quick_verify {
+        use super::*;
+
+        pub(super) const PUBKEY: &str =
+            "032c8c31fc9f990c6b55e3865a184a4ce50e09481f2eaeb3e60ec1cea13a6ae645";
+        // SHA-256("sample")
+        const MESSAGE: &str = "af2bdbe1aa9b6ec1e2ade1d694f41fc71a831d0268e9891562113d8a62add1bf";
+        pub(super) const SIGNATURE: &str =
+            "432310e32cb80eb6503a26ce83cc165c783b870845fb8aad6d970889fcd7a6c8\
+                                 530128b6b81c548874a6305d93ed071ca6e05074d85863d4056ce89b02bfab69";
+
+        #[test]
+        fn test_known_secp256k1_vector() {
+            assert_eq!(
+                quick_verify("secp256k1", PUBKEY, MESSAGE, SIGNATURE),
+                Ok(true)
+            );
+        }
+
+        #[test]
+        fn test_tampered_message() {
+            let message = MESSAGE.replace("af2b", "af2c");
+            assert_eq!(
+                quick_verify("secp256k1", PUBKEY, &message, SIGNATURE),
+                Ok(false)
+            );
+        }
+
+        #[test]
+        fn test_decoding_errors() {
+            assert_eq!(
+                quick_verify("secp255k1", PUBKEY, MESSAGE, SIGNATURE),
+                Err(Error::UnknownCurve)
+            );
+            assert_eq!(
+                quick_verify("secp256k1", "xyz", MESSAGE, SIGNATURE),
+                Err(Error::InvalidHex)
+            );
+            assert_eq!(
+                quick_verify("secp256k1", PUBKEY, MESSAGE, &SIGNATURE[..64]),
+                Err(Error::InvalidEncoding)
+            );
+        }
+    }
+
+    mod test_vectors {
+        use super::*;
+        use crate::curves::secp256k1::create_secp256k1_weierstrass;
+
+        /// A known-answer test in the style of Wycheproof: hex inputs plus the
+        /// expected outcome. The signature is decoded from `r || s` by
+        /// `signature_from_bytes`; a vector whose key or signature fails to
+        /// decode counts as rejected.
+        struct Vector {
+            comment: &'static str,
+            public_key: &'static str,
+            message: &'static str,
+            r: &'static str,
+            s: &'static str,
+            valid: bool,
+        }
+
+        const KEY_1: &str = "032c8c31fc9f990c6b55e3865a184a4ce50e09481f2eaeb3e60ec1cea13a6ae645";
+        const KEY_2: &str = "020121ef709ce7b0ceea109d4994518aa4a28592591ff5380185356c1d57c03cad";
+        // SHA-256("sample")
+        const SAMPLE: &str = "af2bdbe1aa9b6ec1e2ade1d694f41fc71a831d0268e9891562113d8a62add1bf";
+        const R: &str = "432310e32cb80eb6503a26ce83cc165c783b870845fb8aad6d970889fcd7a6c8";
+        const S: &str = "530128b6b81c548874a6305d93ed071ca6e05074d85863d4056ce89b02bfab69";
+        const ZERO: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+        const ONE: &str = "0000000000000000000000000000000000000000000000000000000000000001";
+        const ORDER: &str = "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+
+        const VECTORS: &[Vector] = &[
+            Vector {
+                comment: "valid signature",
+                public_key: KEY_1,
+                message: SAMPLE,
+                r: R,
+                s: S,
+                valid: true,
+            },
+            Vector {
+                // malleable: plain ECDSA accepts both s and n - s
+                comment: "high-s variant",
+                public_key: KEY_1,
+                message: SAMPLE,
+                r: R,
+                s: "acfed74947e3ab778b59cfa26c12f8e213ce8c71d6f03c67ba6575f1cd7695d8",
+                valid: true,
+            },
+            Vector {
+                comment: "zero message hash",
+                public_key: KEY_2,
+                message: ZERO,
+                r: "6a647543cf9252f2807df6310bd565aca78f30695e225962187b20303a3a7e61",
+                s: "a6585948d9ba9c83a406f8e03a703673c025386ac47affca80bfef33744ebc82",
+                valid: true,
+            },
+            Vector {
+                comment: "message hash above the order is reduced",
+                public_key: KEY_2,
+                message: "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+                r: "2a3181f39b18a19da2fe574aaeed8d145cf6adce19f5fd87b4fabf6022d09553",
+                s: "32b168309af169cb05a5b05f7e8f59bb4b920af5fecb503786685de80bcb7165",
+                valid: true,
+            },
+            Vector {
+                comment: "modified message",
+                public_key: KEY_1,
+                message: "af2bdbe1aa9b6ec1e2ade1d694f41fc71a831d0268e9891562113d8a62add1be",
+                r: R,
+                s: S,
+                valid: false,
+            },
+            Vector {
+                comment: "wrong public key",
+                public_key: KEY_2,
+                message: SAMPLE,
+                r: R,
+                s: S,
+                valid: false,
+            },
+            Vector {
+                comment: "r and s swapped",
+                public_key: KEY_1,
+                message: SAMPLE,
+                r: S,
+                s: R,
+                valid: false,
+            },
+            Vector {
+                comment: "r = 0",
+                public_key: KEY_1,
+                message: SAMPLE,
+                r: ZERO,
+                s: S,
+                valid: false,
+            },
+            Vector {
+                comment: "s = 0",
+                public_key: KEY_1,
+                message: SAMPLE,
+                r: R,
+                s: ZERO,
+                valid: false,
+            },
+            Vector {
+                comment: "r = n",
+                public_key: KEY_1,
+                message: SAMPLE,
+                r: ORDER,
+                s: S,
+                valid: false,
+            },
+            Vector {
+                comment: "s = n",
+                public_key: KEY_1,
+                message: SAMPLE,
+                r: R,
+                s: ORDER,
+                valid: false,
+            },
+            Vector {
+                comment: "r = s = 1",
+                public_key: KEY_1,
+                message: SAMPLE,
+                r: ONE,
+                s: ONE,
+                valid: false,
+            },
+            Vector {
+                comment: "truncated signature",
+                public_key: KEY_1,
+                message: SAMPLE,
+                r: R,
+                s: "",
+                valid: false,
+            },
+            Vector {
+                comment: "public key x-coordinate not on the curve",
+                public_key: "020000000000000000000000000000000000000000000000000000000000000005",
+                message: SAMPLE,
+                r: R,
+                s: S,
+                valid: false,
+            },
+        ];
+
+        fn run(ecdsa: &ECDSA<WeierstrassCurve>, vector: &Vector) -> bool {
+            let public_key = match decode_hex(vector.public_key)
+                .and_then(|bytes| ecdsa.curve.from_sec1_compressed(&bytes))
+            {
+                Ok(point) => point,
+                Err(_) => return false,
+            };
+            let signature = match decode_hex(&alloc::format!("{}{}", vector.r, vector.s))
+                .and_then(|bytes| ecdsa.signature_from_bytes(&bytes))
+            {
+                Ok(signature) => signature,
+                Err(_) => return false,
+            };
+            let message = BigUint::from_bytes_be(&decode_hex(vector.message).unwrap());
+
+            ecdsa.verify(&message, &signature, &public_key)
+        }
+
+        #[test]
+        fn test_secp256k1_vectors() {
+            let ecdsa = ECDSA::new(create_secp256k1_weierstrass());
+            for vector in VECTORS {
+                assert_eq!(run(&ecdsa, vector), vector.valid, "{}", vector.comment);
+            }
+        }
+    }
 }
@@ -0,0 +1,73 @@
+use crate::error::Error;
+use alloc::vec;
+use alloc::vec::Vec;
+use num_bigint::BigUint;
+
+/// Decodes a hex string (upper or lower case, no prefix) into bytes
+pub(crate) fn decode_hex(hex: &str) -> Result<Vec<u8>, Error> {
+    if !hex.len().is_multiple_of(2) {
+        return Err(Error::InvalidHex);
+    }
+
+    hex.as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            let high = (pair[0] as char).to_digit(16).ok_or(Error::InvalidHex)?;
+            let low = (pair[1] as char).to_digit(16).ok_or(Error::InvalidHex)?;
+            Ok((high * 16 + low) as u8)
+        })
+        .collect()
+}
+
+/// Encodes a value as big-endian bytes left-padded with zeros to `len` bytes
+pub(crate) fn to_bytes_be_padded(value: &BigUint, len: usize) -> Vec<u8> {
+    let bytes = value.to_bytes_be();
+    let mut padded = vec![0u8; len.saturating_sub(bytes.len())];
+    padded.extend_from_slice(&bytes);
+    padded
+}
+
+/// Returns the number of bytes needed to encode values below `modulus`
+pub(crate) fn byte_length(modulus: &BigUint) -> usize {
+    (modulus.bits() as usize).div_ceil(8)
+}
+
+/// `#[serde(with = ...)]` helpers that (de)serialize a `BigUint` as an
+/// upper-case hex string, matching `CurveParams`
+#[cfg(feature = "serde")]
+pub(crate) mod biguint_hex {
+    use crate::error::Error;
+    use alloc::format;
+    use alloc::string::String;
+    use num_bigint::BigUint;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(value: &BigUint, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("{:X}", value))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BigUint, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        BigUint::parse_bytes(hex.as_bytes(), 16).ok_or_else(|| de::Error::custom(Error::InvalidHex))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_hex() {
+        assert_eq!(decode_hex("00ff10Ab"), Ok(vec![0x00, 0xff, 0x10, 0xab]));
+        assert_eq!(decode_hex(""), Ok(vec![]));
+        assert_eq!(decode_hex("abc"), Err(Error::InvalidHex));
+        assert_eq!(decode_hex("zz"), Err(Error::InvalidHex));
+    }
+
+    #[test]
+    fn test_to_bytes_be_padded() {
+        let value = BigUint::from(0x0102u32);
+        assert_eq!(to_bytes_be_padded(&value, 4), vec![0, 0, 1, 2]);
+        assert_eq!(to_bytes_be_padded(&value, 2), vec![1, 2]);
+    }
+}
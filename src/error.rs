@@ -0,0 +1,62 @@
+use core::fmt;
+
+/// Errors returned across the crate's fallible APIs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// No curve is registered under the requested name
+    UnknownCurve,
+    /// The input is not a valid hex string
+    InvalidHex,
+    /// The bytes do not follow the expected encoding
+    InvalidEncoding,
+    /// The decoded point does not satisfy the curve equation
+    NotOnCurve,
+    /// The point is on the curve but outside the prime-order subgroup
+    NotInSubgroup,
+    /// The value is a quadratic non-residue modulo the field prime
+    NoSquareRoot,
+    /// The value has no multiplicative inverse modulo the modulus
+    NoInverse,
+    /// The scalar is outside the range the operation accepts
+    InvalidScalar,
+    /// The public key does not match the one derived from the private key
+    KeyMismatch,
+    /// The point is the point at infinity where a finite point is required
+    Identity,
+    /// The curve parameters give a singular curve
+    SingularCurve,
+    /// The modulus is not an odd prime
+    InvalidModulus,
+    /// Signing produced `r = 0` or `s = 0`; a different nonce is needed
+    ZeroSignatureComponent,
+    /// Reading the message to sign failed
+    ReadFailed,
+}
+
+/// The name this error type had before it covered the whole crate
+pub type EccError = Error;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Error::UnknownCurve => "unknown curve name",
+            Error::InvalidHex => "invalid hex string",
+            Error::InvalidEncoding => "invalid encoding",
+            Error::NotOnCurve => "point is not on the curve",
+            Error::NotInSubgroup => "point is not in the prime-order subgroup",
+            Error::NoSquareRoot => "value has no square root",
+            Error::NoInverse => "value has no multiplicative inverse",
+            Error::InvalidScalar => "scalar is out of range",
+            Error::KeyMismatch => "public key does not match the private key",
+            Error::Identity => "unexpected point at infinity",
+            Error::SingularCurve => "curve is singular",
+            Error::InvalidModulus => "modulus is not an odd prime",
+            Error::ZeroSignatureComponent => "signature component r or s is zero",
+            Error::ReadFailed => "failed to read the message",
+        };
+        f.write_str(message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
@@ -1,19 +1,42 @@
+use crate::encoding::{byte_length, to_bytes_be_padded};
+use crate::error::Error;
+use alloc::vec;
+use alloc::vec::Vec;
 use log::debug;
-use num_bigint::BigUint;
-use num_traits::{identities::Zero, One};
+use num_bigint::{BigUint, RandBigInt};
+use num_traits::{identities::Zero, One, ToPrimitive};
+use rand::RngCore;
+
+/// Miller-Rabin bases used by `sqrt_checked`: the first twelve primes, which
+/// make the test deterministic below 3.3 * 10^24
+const MILLER_RABIN_BASES: [u32; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// The largest modulus `FiniteField::inverse_table` builds a table for
+const INVERSE_TABLE_LIMIT: u32 = 1 << 20;
 
 pub struct FiniteField;
 
 impl FiniteField {
+    /// Returns `(a + b) mod p`. For reduced inputs the sum is below `2p`, so
+    /// it is brought into range with at most one subtraction; only larger
+    /// sums pay for a division.
     pub fn add(a: &BigUint, b: &BigUint, p: &BigUint) -> BigUint {
-        (a + b) % p
+        let sum = a + b;
+        if &sum < p {
+            sum
+        } else if sum < (p << 1) {
+            sum - p
+        } else {
+            sum % p
+        }
     }
 
     pub fn sub(a: &BigUint, b: &BigUint, p: &BigUint) -> BigUint {
+        let (a, b) = (a % p, b % p);
         if a >= b {
-            (a - b) % p
+            a - b
         } else {
-            (p - (b - a) % p) % p
+            p - (b - a)
         }
     }
 
@@ -25,11 +48,76 @@ impl FiniteField {
         Self::mul(a, &Self::inv_mul(b, p), p)
     }
 
+    /// Like `div`, but returns `Error::NoInverse` instead of panicking when `b`
+    /// is not invertible modulo `p`
+    pub fn try_div(a: &BigUint, b: &BigUint, p: &BigUint) -> Result<BigUint, Error> {
+        Ok(Self::mul(a, &Self::try_inv_mul(b, p)?, p))
+    }
+
     pub fn exp(a: &BigUint, b: &BigUint, p: &BigUint) -> BigUint {
         a.modpow(b, p)
     }
 
+    /// Exponentiation for secret exponents.
+    ///
+    /// Runs a Montgomery ladder over a fixed number of exponent bits, the bit
+    /// length of `p` (or of `b` if longer), doing one multiplication and one
+    /// squaring per bit whatever its value. This hides the exponent's length
+    /// and Hamming weight from the operation count, although the underlying
+    /// `BigUint` arithmetic is not itself constant time.
+    pub fn exp_ct(a: &BigUint, b: &BigUint, p: &BigUint) -> BigUint {
+        let mut r0 = BigUint::one() % p;
+        let mut r1 = a % p;
+        let limbs = p.bits().div_ceil(32) as usize;
+
+        for i in (0..p.bits().max(b.bits())).rev() {
+            let bit = b.bit(i);
+            Self::conditional_swap(&mut r0, &mut r1, bit, limbs);
+            r1 = Self::mul(&r0, &r1, p);
+            r0 = Self::mul(&r0, &r0, p);
+            Self::conditional_swap(&mut r0, &mut r1, bit, limbs);
+        }
+
+        r0
+    }
+
+    /// Swaps `a` and `b` when `swap` is set, without branching on it.
+    ///
+    /// Both values are spread over `limbs` 32-bit limbs, which must be enough
+    /// to hold either, and every limb pair is exchanged under a mask that is
+    /// all ones or all zeros, so the same operations run either way.
+    fn conditional_swap(a: &mut BigUint, b: &mut BigUint, swap: bool, limbs: usize) {
+        let mask = u32::from(swap).wrapping_neg();
+        let mut a_limbs = a.to_u32_digits();
+        let mut b_limbs = b.to_u32_digits();
+        a_limbs.resize(limbs, 0);
+        b_limbs.resize(limbs, 0);
+        for (x, y) in a_limbs.iter_mut().zip(b_limbs.iter_mut()) {
+            let t = (*x ^ *y) & mask;
+            *x ^= t;
+            *y ^= t;
+        }
+        *a = BigUint::new(a_limbs);
+        *b = BigUint::new(b_limbs);
+    }
+
     pub fn inv_mul(a: &BigUint, p: &BigUint) -> BigUint {
+        match Self::try_inv_mul(a, p) {
+            Ok(inverse) => inverse,
+            Err(_) => panic!("Multiplicative inverse does not exist"),
+        }
+    }
+
+    /// Inverts `a` as `a^(p-2) mod p` by Fermat's little theorem. Unlike the
+    /// extended Euclidean algorithm in `inv_mul`, the sequence of operations
+    /// does not depend on `a`. Only valid for a prime `p`; zero maps to zero.
+    pub fn inv_mul_fermat(a: &BigUint, p: &BigUint) -> BigUint {
+        a.modpow(&(p - 2u32), p)
+    }
+
+    /// Returns the multiplicative inverse of `a` modulo `p`, or
+    /// `Error::NoInverse` when `gcd(a, p) != 1`
+    pub fn try_inv_mul(a: &BigUint, p: &BigUint) -> Result<BigUint, Error> {
         let (mut t, mut r) = ((BigUint::zero(), BigUint::one()), (p.clone(), a.clone()));
 
         while !r.1.is_zero() {
@@ -38,11 +126,34 @@ impl FiniteField {
             t = (t.1.clone(), Self::sub(&t.0, &(&q * &t.1), p));
         }
 
-        if r.0 > BigUint::one() {
-            panic!("Multiplicative inverse does not exist");
+        if !r.0.is_one() {
+            return Err(Error::NoInverse);
         }
 
-        t.0 % p
+        Ok(t.0 % p)
+    }
+
+    /// Precomputes the inverses of `1..p` modulo a small prime `p`, so that
+    /// `table[i]` is the inverse of `i`; `table[0]` is 0.
+    ///
+    /// Meant for exhaustive work on toy curves, where the same inverses come
+    /// up over and over. Uses `inv(i) = -(p / i)·inv(p mod i)`, one
+    /// multiplication per entry. Panics if `p` is above 2^20.
+    pub fn inverse_table(p: &BigUint) -> Vec<BigUint> {
+        assert!(
+            p <= &BigUint::from(INVERSE_TABLE_LIMIT),
+            "inverse tables are limited to moduli up to 2^20"
+        );
+        let p = p.to_u64().expect("p is at most 2^20");
+        let mut table = vec![0u64; p as usize];
+        if p > 1 {
+            table[1] = 1;
+        }
+        for i in 2..p {
+            let quotient_inv = (p / i) * table[(p % i) as usize] % p;
+            table[i as usize] = (p - quotient_inv) % p;
+        }
+        table.into_iter().map(BigUint::from).collect()
     }
 
     pub fn inv_add(a: &BigUint, p: &BigUint) -> BigUint {
@@ -53,18 +164,245 @@ impl FiniteField {
         }
     }
 
+    /// Compares two values below `p` without branching on their contents.
+    ///
+    /// Both values are encoded as big-endian bytes padded to the byte length
+    /// of `p`, and every byte pair is folded into one difference, so the time
+    /// taken depends only on `p`. A value too long for that width cannot equal
+    /// one that fits, so differing encoded lengths compare unequal.
+    pub fn ct_eq(a: &BigUint, b: &BigUint, p: &BigUint) -> bool {
+        let len = byte_length(p);
+        let a = to_bytes_be_padded(a, len);
+        let b = to_bytes_be_padded(b, len);
+        if a.len() != b.len() {
+            return false;
+        }
+        let diff = a.iter().zip(&b).fold(0u8, |acc, (x, y)| acc | (x ^ y));
+        core::hint::black_box(diff) == 0
+    }
+
+    /// Returns a square root of `a` modulo the prime `p`, or `None` for a
+    /// non-residue. Of the two roots `r` and `p - r` the smaller one is
+    /// returned, whichever algorithm `p` calls for.
     pub fn sqrt(a: &BigUint, p: &BigUint) -> Option<BigUint> {
+        // an unreduced multiple of p would otherwise reach Tonelli-Shanks as
+        // t = 0, which never squares to 1 and is reported as a non-residue
+        let a = &(a % p);
         if a.is_zero() || a.is_one() {
             return Some(a.clone());
         }
 
-        if p % 4u32 == BigUint::from(3u32) {
+        let root = if p % 4u32 == BigUint::from(3u32) {
             Self::sqrt_for_p_mod_4_eq_3(a, p)
         } else {
             Self::sqrt_tonelli_shanks(a, p)
+        }?;
+        Some(if root <= p / 2u32 { root } else { p - root })
+    }
+
+    /// Like `sqrt`, but returns `Error::NoSquareRoot` for a non-residue
+    pub fn try_sqrt(a: &BigUint, p: &BigUint) -> Result<BigUint, Error> {
+        Self::sqrt(a, p).ok_or(Error::NoSquareRoot)
+    }
+
+    /// Returns a square root of `a` modulo `p^k` for an odd prime `p`, or `None`
+    /// if there is none.
+    ///
+    /// A root modulo `p` of the unit part is lifted with Hensel's lemma,
+    /// `r' = r - (r^2 - a) / 2r`, doubling the precision each step. A factor
+    /// `p^v` of `a` (v < k) needs `v` even and contributes `p^(v/2)` to the root.
+    pub fn sqrt_prime_power(a: &BigUint, p: &BigUint, k: u32) -> Option<BigUint> {
+        assert!(p.bit(0), "sqrt_prime_power needs an odd prime");
+        let modulus = p.pow(k);
+        let mut a = a % &modulus;
+        if a.is_zero() {
+            return Some(a);
+        }
+
+        let mut v = 0;
+        while (&a % p).is_zero() {
+            a /= p;
+            v += 1;
+        }
+        if v % 2 == 1 {
+            return None;
+        }
+
+        // a is now a unit modulo p^(k - v)
+        let unit_k = k - v;
+        let mut root = Self::sqrt(&a, p)?;
+        let mut precision = 1;
+        while precision < unit_k {
+            precision = (2 * precision).min(unit_k);
+            let m = p.pow(precision);
+            let residual = Self::sub(&Self::mul(&root, &root, &m), &a, &m);
+            let step = Self::div(&residual, &Self::add(&root, &root, &m), &m);
+            root = Self::sub(&root, &step, &m);
+        }
+
+        Some(Self::mul(&root, &p.pow(v / 2), &modulus))
+    }
+
+    /// Like `sqrt`, but first checks that `p` is an odd prime with a Miller-Rabin
+    /// test. `sqrt` assumes a prime modulus and may loop forever otherwise.
+    pub fn sqrt_checked(a: &BigUint, p: &BigUint) -> Result<Option<BigUint>, Error> {
+        if p == &BigUint::from(2u32) || !Self::passes_miller_rabin(p) {
+            return Err(Error::InvalidModulus);
+        }
+        Ok(Self::sqrt(a, p))
+    }
+
+    /// Returns the Legendre symbol `(a / p)` for an odd prime `p`: 0 if `p`
+    /// divides `a`, 1 for a nonzero square and -1 for a non-residue
+    pub fn legendre_symbol(a: &BigUint, p: &BigUint) -> i8 {
+        Self::jacobi_symbol(a, p)
+    }
+
+    /// Checks whether `a` has a square root modulo the odd prime `p`, which is
+    /// much cheaper than running `sqrt` to find out. Zero counts as a residue.
+    pub fn is_quadratic_residue(a: &BigUint, p: &BigUint) -> bool {
+        Self::legendre_symbol(a, p) != -1
+    }
+
+    /// Returns the Jacobi symbol `(a / n)` for an odd positive `n`: 0 if `a` and
+    /// `n` share a factor, otherwise ±1. For a prime `n` this is the Legendre
+    /// symbol, but for composite `n` a value of 1 does not imply `a` is a square.
+    ///
+    /// Uses quadratic reciprocity, pulling out factors of 2 with the second
+    /// supplement, so no factorization of `n` is needed.
+    pub fn jacobi_symbol(a: &BigUint, n: &BigUint) -> i8 {
+        assert!(n.bit(0), "Jacobi symbol needs an odd positive modulus");
+
+        let (mut a, mut n) = (a % n, n.clone());
+        let mut result = 1i8;
+        while !a.is_zero() {
+            let twos = a.trailing_zeros().unwrap_or(0);
+            a >>= twos;
+            let n_mod_8 = (&n % 8u32).to_u32_digits().first().copied().unwrap_or(0);
+            if twos % 2 == 1 && (n_mod_8 == 3 || n_mod_8 == 5) {
+                result = -result;
+            }
+
+            // reciprocity: (a / n) = -(n / a) exactly when a ≡ n ≡ 3 (mod 4)
+            core::mem::swap(&mut a, &mut n);
+            if a.bit(0) && a.bit(1) && n.bit(0) && n.bit(1) {
+                result = -result;
+            }
+            a %= &n;
+        }
+
+        if n.is_one() {
+            result
+        } else {
+            0
         }
     }
 
+    /// Evaluates the polynomial with coefficients `coeffs`, constant term
+    /// first, at `x` modulo `p` by Horner's method
+    pub fn poly_eval(coeffs: &[BigUint], x: &BigUint, p: &BigUint) -> BigUint {
+        coeffs.iter().rev().fold(BigUint::zero(), |acc, c| {
+            Self::add(&Self::mul(&acc, x, p), &(c % p), p)
+        })
+    }
+
+    /// Evaluates at `x` the unique polynomial of degree below `points.len()`
+    /// passing through `points`, by Lagrange interpolation modulo a prime `p`.
+    ///
+    /// Panics if two points share an x-coordinate modulo `p`.
+    pub fn lagrange_interpolate(
+        points: &[(BigUint, BigUint)],
+        x: &BigUint,
+        p: &BigUint,
+    ) -> BigUint {
+        points
+            .iter()
+            .enumerate()
+            .fold(BigUint::zero(), |acc, (i, (xi, yi))| {
+                // the basis polynomial for point i at x: Π (x - x_j) / (x_i - x_j)
+                let (numerator, denominator) =
+                    points.iter().enumerate().filter(|(j, _)| *j != i).fold(
+                        (BigUint::one(), BigUint::one()),
+                        |(num, den), (_, (xj, _))| {
+                            (
+                                Self::mul(&num, &Self::sub(x, xj, p), p),
+                                Self::mul(&den, &Self::sub(xi, xj, p), p),
+                            )
+                        },
+                    );
+                let basis = Self::mul(&numerator, &Self::inv_mul(&denominator, p), p);
+                Self::add(&acc, &Self::mul(yi, &basis, p), p)
+            })
+    }
+
+    /// Probabilistic primality test: trial division by small primes, then
+    /// `rounds` Miller-Rabin rounds with random bases. A composite passes with
+    /// probability at most 4^-rounds.
+    #[cfg(feature = "std")]
+    pub fn is_probable_prime(n: &BigUint, rounds: usize) -> bool {
+        Self::is_probable_prime_with_rng(n, rounds, &mut rand::thread_rng())
+    }
+
+    /// `is_probable_prime` with a caller-supplied source of random bases
+    pub fn is_probable_prime_with_rng<R: RngCore>(n: &BigUint, rounds: usize, rng: &mut R) -> bool {
+        if let Some(result) = Self::trial_division(n) {
+            return result;
+        }
+
+        let upper = n - BigUint::one();
+        (0..rounds).all(|_| {
+            let base = rng.gen_biguint_range(&BigUint::from(2u32), &upper);
+            Self::is_strong_probable_prime(n, &base)
+        })
+    }
+
+    /// Runs Miller-Rabin on `n` with each of `MILLER_RABIN_BASES`
+    fn passes_miller_rabin(n: &BigUint) -> bool {
+        if let Some(result) = Self::trial_division(n) {
+            return result;
+        }
+        MILLER_RABIN_BASES
+            .iter()
+            .all(|&base| Self::is_strong_probable_prime(n, &BigUint::from(base)))
+    }
+
+    /// Settles primality for `n` below 2 or sharing a factor with the small
+    /// primes in `MILLER_RABIN_BASES`, and returns `None` otherwise
+    fn trial_division(n: &BigUint) -> Option<bool> {
+        if n < &BigUint::from(2u32) {
+            return Some(false);
+        }
+        for base in MILLER_RABIN_BASES {
+            if n == &BigUint::from(base) {
+                return Some(true);
+            }
+            if (n % base).is_zero() {
+                return Some(false);
+            }
+        }
+        None
+    }
+
+    /// One Miller-Rabin round: writes n - 1 = d·2^s with d odd and checks that
+    /// base^d = 1 or base^(d·2^r) = -1 for some r < s
+    fn is_strong_probable_prime(n: &BigUint, base: &BigUint) -> bool {
+        let n_minus_1 = n - BigUint::one();
+        let s = n_minus_1.trailing_zeros().unwrap_or(0);
+        let d = &n_minus_1 >> s;
+
+        let mut x = base.modpow(&d, n);
+        if x.is_one() || x == n_minus_1 {
+            return true;
+        }
+        for _ in 1..s {
+            x = Self::mul(&x, &x, n);
+            if x == n_minus_1 {
+                return true;
+            }
+        }
+        false
+    }
+
     fn sqrt_for_p_mod_4_eq_3(a: &BigUint, p: &BigUint) -> Option<BigUint> {
         let exp = (p + BigUint::one()) / 4u32;
         let root = a.modpow(&exp, p);
@@ -75,7 +413,7 @@ impl FiniteField {
         );
 
         if root_squared == *a {
-            Some(if root <= p / 2u32 { root } else { p - &root })
+            Some(root)
         } else {
             None
         }
@@ -136,6 +474,10 @@ impl FiniteField {
         z
     }
 
+    // Returns the least i < m with t^(2^i) = 1, or m if there is none. The loop
+    // keeps t^(2^(m-1)) = 1 for a quadratic residue, so i < m always holds
+    // there. For a non-residue the first t = a^q has order exactly 2^s = 2^m,
+    // so the first call returns m. That makes i == m a correct "no root" signal.
     fn find_least_i(t: &BigUint, m: &u32, p: &BigUint) -> u32 {
         let mut i = 0u32;
         let mut zz = t.clone();
@@ -147,10 +489,140 @@ impl FiniteField {
     }
 }
 
+/// A prime field `F_p` that the Weierstrass group law computes in.
+///
+/// Coordinates cross the curve API as `BigUint`, but an implementation can
+/// keep `Element` in any representation, e.g. fixed-width limbs for a known
+/// prime, and `WeierstrassCurve<F>` runs its addition law on those.
+pub trait PrimeField {
+    type Element: Clone + PartialEq;
+
+    fn modulus(&self) -> &BigUint;
+    /// Converts `value` into the field, reducing it modulo the prime
+    fn element(&self, value: &BigUint) -> Self::Element;
+    fn to_biguint(&self, a: &Self::Element) -> BigUint;
+    fn is_zero(&self, a: &Self::Element) -> bool;
+    fn add(&self, a: &Self::Element, b: &Self::Element) -> Self::Element;
+    fn sub(&self, a: &Self::Element, b: &Self::Element) -> Self::Element;
+    fn mul(&self, a: &Self::Element, b: &Self::Element) -> Self::Element;
+    /// Multiplicative inverse; panics on zero
+    fn inv(&self, a: &Self::Element) -> Self::Element;
+
+    fn square(&self, a: &Self::Element) -> Self::Element {
+        self.mul(a, a)
+    }
+
+    fn neg(&self, a: &Self::Element) -> Self::Element {
+        self.sub(&self.element(&BigUint::zero()), a)
+    }
+}
+
+/// The default `PrimeField`: `BigUint` values reduced modulo `p`, using the
+/// `FiniteField` operations
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BigUintField {
+    p: BigUint,
+}
+
+impl BigUintField {
+    pub fn new(p: BigUint) -> Self {
+        BigUintField { p }
+    }
+}
+
+impl PrimeField for BigUintField {
+    type Element = BigUint;
+
+    fn modulus(&self) -> &BigUint {
+        &self.p
+    }
+
+    fn element(&self, value: &BigUint) -> BigUint {
+        value % &self.p
+    }
+
+    fn to_biguint(&self, a: &BigUint) -> BigUint {
+        a.clone()
+    }
+
+    fn is_zero(&self, a: &BigUint) -> bool {
+        a.is_zero()
+    }
+
+    fn add(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        FiniteField::add(a, b, &self.p)
+    }
+
+    fn sub(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        FiniteField::sub(a, b, &self.p)
+    }
+
+    fn mul(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        FiniteField::mul(a, b, &self.p)
+    }
+
+    fn inv(&self, a: &BigUint) -> BigUint {
+        FiniteField::inv_mul(a, &self.p)
+    }
+
+    fn neg(&self, a: &BigUint) -> BigUint {
+        FiniteField::inv_add(a, &self.p)
+    }
+}
+
+/// Arithmetic modulo a curve's group order `n`.
+///
+/// Scalars (private keys, nonces, signature components) live here rather than
+/// in the coordinate field mod `p`; keeping the modulus in the type avoids
+/// reducing by the wrong one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScalarField {
+    order: BigUint,
+}
+
+impl ScalarField {
+    pub fn new(order: BigUint) -> Self {
+        ScalarField { order }
+    }
+
+    pub fn order(&self) -> &BigUint {
+        &self.order
+    }
+
+    pub fn reduce(&self, a: &BigUint) -> BigUint {
+        a % &self.order
+    }
+
+    pub fn add(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        FiniteField::add(a, b, &self.order)
+    }
+
+    pub fn sub(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        FiniteField::sub(a, b, &self.order)
+    }
+
+    pub fn mul(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        FiniteField::mul(a, b, &self.order)
+    }
+
+    pub fn neg(&self, a: &BigUint) -> BigUint {
+        FiniteField::inv_add(a, &self.order)
+    }
+
+    /// Multiplicative inverse; panics for scalars with no inverse, e.g. zero
+    pub fn inv(&self, a: &BigUint) -> BigUint {
+        FiniteField::inv_mul(a, &self.order)
+    }
+
+    /// Like `inv`, but returns `Error::NoInverse` when `gcd(a, order) != 1`
+    pub fn try_inv(&self, a: &BigUint) -> Result<BigUint, Error> {
+        FiniteField::try_inv_mul(a, &self.order)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use env_logger;
 
     fn init() {
         let _ = env_logger::builder().is_test(true).try_init();
@@ -178,6 +650,23 @@ mod test {
         assert_eq!(FiniteField::sub(&d, &c, &p), BigUint::from(6u32));
     }
 
+    #[test]
+    fn test_sub_with_unreduced_inputs() {
+        let p = BigUint::from(11u32);
+        let b = &p * 2u32 + 3u32;
+
+        assert_eq!(
+            FiniteField::sub(&BigUint::zero(), &b, &p),
+            BigUint::from(8u32)
+        );
+        assert_eq!(FiniteField::sub(&(&p + 1u32), &b, &p), BigUint::from(9u32));
+        assert_eq!(
+            FiniteField::sub(&b, &BigUint::zero(), &p),
+            BigUint::from(3u32)
+        );
+        assert_eq!(FiniteField::sub(&b, &b, &p), BigUint::zero());
+    }
+
     #[test]
     fn test_inv_add_with_larger_prime() {
         let c = BigUint::from(4u32);
@@ -252,6 +741,194 @@ mod test {
         assert_eq!(FiniteField::sqrt(&BigUint::one(), &p), Some(BigUint::one()));
     }
 
+    #[test]
+    fn test_exp_ct_matches_exp() {
+        use num_bigint::RandBigInt;
+
+        let mut rng = rand::thread_rng();
+        for p in [
+            BigUint::from(11u32),
+            BigUint::from(1009u32),
+            BigUint::parse_bytes(
+                b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+                16,
+            )
+            .unwrap(),
+        ] {
+            for _ in 0..10 {
+                let a = rng.gen_biguint_below(&p);
+                let b = rng.gen_biguint_below(&p);
+                assert_eq!(
+                    FiniteField::exp_ct(&a, &b, &p),
+                    FiniteField::exp(&a, &b, &p)
+                );
+            }
+            assert_eq!(
+                FiniteField::exp_ct(&BigUint::from(3u32), &BigUint::zero(), &p),
+                BigUint::one()
+            );
+        }
+    }
+
+    #[test]
+    fn test_conditional_swap() {
+        let values = [
+            BigUint::zero(),
+            BigUint::from(7u32),
+            BigUint::from(u64::MAX),
+            (BigUint::one() << 95) + 3u32,
+        ];
+        for a in &values {
+            for b in &values {
+                let (mut x, mut y) = (a.clone(), b.clone());
+                FiniteField::conditional_swap(&mut x, &mut y, false, 3);
+                assert_eq!((&x, &y), (a, b));
+
+                FiniteField::conditional_swap(&mut x, &mut y, true, 3);
+                assert_eq!((&x, &y), (b, a));
+            }
+        }
+    }
+
+    #[test]
+    fn test_ct_eq_agrees_with_eq() {
+        let values = [
+            BigUint::zero(),
+            BigUint::one(),
+            BigUint::from(255u32),
+            BigUint::from(256u32),
+            BigUint::from(0x1_0000_0001u64),
+            BigUint::one() << 255,
+            (BigUint::one() << 256) - 1u32,
+        ];
+
+        let p = BigUint::one() << 256;
+        for a in &values {
+            for b in &values {
+                assert_eq!(FiniteField::ct_eq(a, b, &p), a == b, "{} vs {}", a, b);
+                // a narrower modulus leaves the wider values unpadded
+                assert_eq!(
+                    FiniteField::ct_eq(a, b, &BigUint::from(257u32)),
+                    a == b,
+                    "{} vs {}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_sqrt_checked_rejects_composite_modulus() {
+        for composite in [1u32, 2, 9, 15, 21, 25, 561, 1105] {
+            assert_eq!(
+                FiniteField::sqrt_checked(&BigUint::from(4u32), &BigUint::from(composite)),
+                Err(Error::InvalidModulus),
+                "modulus {}",
+                composite
+            );
+        }
+
+        // 2^61 - 1 is prime, 2^67 - 1 = 193707721 * 761838257287 is not
+        let prime = (BigUint::one() << 61) - 1u32;
+        let composite = (BigUint::one() << 67) - 1u32;
+        let square = BigUint::from(49u32);
+        assert_eq!(
+            FiniteField::sqrt_checked(&square, &prime),
+            Ok(FiniteField::sqrt(&square, &prime))
+        );
+        assert_eq!(
+            FiniteField::sqrt_checked(&square, &composite),
+            Err(Error::InvalidModulus)
+        );
+        assert_eq!(
+            FiniteField::sqrt_checked(&BigUint::from(2u32), &BigUint::from(11u32)),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn test_is_probable_prime() {
+        let primes = [
+            BigUint::from(2u32),
+            BigUint::from(3u32),
+            BigUint::from(41u32),
+            BigUint::from(1009u32),
+            (BigUint::one() << 61) - 1u32,
+            (BigUint::one() << 127) - 1u32,
+            BigUint::parse_bytes(
+                b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+                16,
+            )
+            .unwrap(),
+        ];
+        for n in &primes {
+            assert!(FiniteField::is_probable_prime(n, 20), "{} is prime", n);
+        }
+
+        // includes Carmichael numbers, which fool the Fermat test, and
+        // 3215031751, a strong pseudoprime to bases 2, 3, 5 and 7
+        let composites = [
+            0u64,
+            1,
+            4,
+            1681,
+            561,
+            1105,
+            1729,
+            2465,
+            2821,
+            6601,
+            8911,
+            41041,
+            3_215_031_751,
+        ];
+        for n in composites {
+            assert!(
+                !FiniteField::is_probable_prime(&BigUint::from(n), 20),
+                "{} is composite",
+                n
+            );
+        }
+        assert!(!FiniteField::is_probable_prime(
+            &((BigUint::one() << 67) - 1u32),
+            20
+        ));
+    }
+
+    #[test]
+    fn test_tonelli_shanks_for_p_1_mod_8() {
+        use num_bigint::RandBigInt;
+
+        let mut rng = rand::thread_rng();
+        let mut primes: Vec<BigUint> = (17u32..2000)
+            .filter(|&n| n % 8 == 1 && (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0))
+            .map(BigUint::from)
+            .collect();
+        assert!(primes.len() > 50);
+        // high 2-adic valuations: 15·2^27 + 1, 3·2^30 + 1 and 2^64 - 2^32 + 1
+        primes.push(BigUint::from(2_013_265_921u64));
+        primes.push(BigUint::from(3_221_225_473u64));
+        primes.push(BigUint::from(0xFFFF_FFFF_0000_0001u64));
+
+        for p in &primes {
+            let half = (p - 1u32) / 2u32;
+            for _ in 0..20 {
+                let x = rng.gen_biguint_below(p);
+                let square = FiniteField::mul(&x, &x, p);
+                let root = FiniteField::sqrt(&square, p).unwrap();
+                assert_eq!(FiniteField::mul(&root, &root, p), square, "p = {}", p);
+
+                // Euler's criterion decides which values have roots at all
+                let is_residue = x.is_zero() || x.modpow(&half, p).is_one();
+                assert_eq!(FiniteField::sqrt(&x, p).is_some(), is_residue, "p = {}", p);
+            }
+
+            // multiples of p are reduced before taking the root
+            assert_eq!(FiniteField::sqrt(&(p * 3u32), p), Some(BigUint::zero()));
+        }
+    }
+
     #[test]
     fn test_inv_add() {
         let c = BigUint::from(4u32);
@@ -260,4 +937,315 @@ mod test {
         assert_eq!(c_inv, BigUint::from(47u32));
         assert_eq!(FiniteField::add(&c_inv, &c, &p), BigUint::zero());
     }
+
+    #[test]
+    fn test_fallible_division_and_sqrt() {
+        let p = BigUint::from(11u32);
+        assert_eq!(
+            FiniteField::try_inv_mul(&BigUint::from(3u32), &p),
+            Ok(BigUint::from(4u32))
+        );
+        assert_eq!(
+            FiniteField::try_div(&BigUint::from(6u32), &BigUint::from(3u32), &p),
+            Ok(BigUint::from(2u32))
+        );
+        assert_eq!(
+            FiniteField::try_div(&BigUint::one(), &BigUint::zero(), &p),
+            Err(Error::NoInverse)
+        );
+        assert_eq!(
+            FiniteField::try_inv_mul(&BigUint::from(6u32), &BigUint::from(9u32)),
+            Err(Error::NoInverse)
+        );
+
+        // 2 is a non-residue mod 11, 5 = 4^2
+        assert_eq!(
+            FiniteField::try_sqrt(&BigUint::from(2u32), &p),
+            Err(Error::NoSquareRoot)
+        );
+        let root = FiniteField::try_sqrt(&BigUint::from(5u32), &p).unwrap();
+        assert_eq!(FiniteField::mul(&root, &root, &p), BigUint::from(5u32));
+    }
+
+    #[test]
+    fn test_jacobi_symbol_tables() {
+        let tables: [(u32, &[i8]); 4] = [
+            (1, &[1]),
+            (9, &[0, 1, 1, 0, 1, 1, 0, 1, 1]),
+            (15, &[0, 1, 1, 0, 1, 0, 0, -1, 1, 0, 0, -1, 0, -1, -1]),
+            (
+                21,
+                &[
+                    0, 1, -1, 0, 1, 1, 0, 0, -1, 0, -1, -1, 0, -1, 0, 0, 1, 1, 0, -1, 1,
+                ],
+            ),
+        ];
+        for (n, row) in tables {
+            for (a, expected) in row.iter().enumerate() {
+                assert_eq!(
+                    FiniteField::jacobi_symbol(&BigUint::from(a), &BigUint::from(n)),
+                    *expected,
+                    "({} / {})",
+                    a,
+                    n
+                );
+            }
+        }
+
+        let jacobi =
+            |a: u32, n: u32| FiniteField::jacobi_symbol(&BigUint::from(a), &BigUint::from(n));
+        assert_eq!(jacobi(1001, 9907), -1);
+        assert_eq!(jacobi(19, 45), 1);
+        assert_eq!(jacobi(8, 21), -1);
+        assert_eq!(jacobi(30, 59 * 61), 1);
+        // reduces a first
+        assert_eq!(jacobi(15 + 7, 15), -1);
+    }
+
+    #[test]
+    fn test_jacobi_symbol_matches_euler_criterion_for_primes() {
+        for p in [3u32, 5, 7, 11, 13, 101] {
+            let p_big = BigUint::from(p);
+            for a in 0..p {
+                let euler =
+                    FiniteField::exp(&BigUint::from(a), &BigUint::from((p - 1) / 2), &p_big);
+                let expected = if a == 0 {
+                    0
+                } else if euler.is_one() {
+                    1
+                } else {
+                    -1
+                };
+                assert_eq!(
+                    FiniteField::jacobi_symbol(&BigUint::from(a), &p_big),
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "odd positive modulus")]
+    fn test_jacobi_symbol_rejects_even_modulus() {
+        FiniteField::jacobi_symbol(&BigUint::from(3u32), &BigUint::from(10u32));
+    }
+
+    #[test]
+    fn test_sqrt_prime_power() {
+        let (p, p2) = (BigUint::from(7u32), BigUint::from(49u32));
+
+        // 2 = 3^2 mod 7 lifts to a root of 2 mod 49
+        let root = FiniteField::sqrt_prime_power(&BigUint::from(2u32), &p, 2).unwrap();
+        assert_eq!(FiniteField::mul(&root, &root, &p2), BigUint::from(2u32));
+
+        // every residue modulo 49 agrees with brute force on solvability
+        for a in 0u32..49 {
+            let a = BigUint::from(a);
+            let exists = (0u32..49)
+                .any(|x| FiniteField::mul(&BigUint::from(x), &BigUint::from(x), &p2) == a);
+            match FiniteField::sqrt_prime_power(&a, &p, 2) {
+                Some(root) => assert_eq!(FiniteField::mul(&root, &root, &p2), a),
+                None => assert!(!exists, "missed a root of {}", a),
+            }
+        }
+
+        // higher powers, including a factor of p^2 in a
+        let p5 = p.pow(5);
+        for a in [2u32, 11, 49 * 2, 49 * 49 * 4] {
+            let a = BigUint::from(a);
+            let root = FiniteField::sqrt_prime_power(&a, &p, 5).unwrap();
+            assert_eq!(FiniteField::mul(&root, &root, &p5), &a % &p5);
+        }
+
+        // 3 is a non-residue mod 7, and 7 has an odd power of p
+        assert_eq!(
+            FiniteField::sqrt_prime_power(&BigUint::from(3u32), &p, 2),
+            None
+        );
+        assert_eq!(
+            FiniteField::sqrt_prime_power(&BigUint::from(7u32), &p, 2),
+            None
+        );
+    }
+
+    #[test]
+    fn test_scalar_field_matches_finite_field() {
+        use crate::curves::secp256k1::create_secp256k1_weierstrass;
+        use crate::ec::EllipticCurve;
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let n = create_secp256k1_weierstrass().order().clone();
+        let scalars = ScalarField::new(n.clone());
+        let mut rng = StdRng::seed_from_u64(64);
+
+        for _ in 0..16 {
+            let a = rng.gen_biguint_below(&n);
+            let b = rng.gen_biguint_below(&n);
+            assert_eq!(scalars.add(&a, &b), FiniteField::add(&a, &b, &n));
+            assert_eq!(scalars.sub(&a, &b), FiniteField::sub(&a, &b, &n));
+            assert_eq!(scalars.mul(&a, &b), FiniteField::mul(&a, &b, &n));
+            assert_eq!(scalars.neg(&a), FiniteField::inv_add(&a, &n));
+            assert_eq!(scalars.inv(&a), FiniteField::inv_mul(&a, &n));
+            assert_eq!(scalars.reduce(&(&a + &n)), a);
+        }
+        assert_eq!(scalars.order(), &n);
+    }
+
+    #[test]
+    fn test_sqrt_returns_smaller_root_for_both_algorithms() {
+        // 13 ≡ 1 (mod 4) goes through Tonelli-Shanks, 23 ≡ 3 (mod 4) does not
+        for p in [13u32, 17, 41, 23, 31, 43] {
+            let modulus = BigUint::from(p);
+            for a in 1..p {
+                let a = BigUint::from(a);
+                if let Some(root) = FiniteField::sqrt(&a, &modulus) {
+                    assert_eq!(FiniteField::mul(&root, &root, &modulus), a);
+                    assert!(root <= &modulus / 2u32, "sqrt({}) mod {} = {}", a, p, root);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_quadratic_residue_agrees_with_sqrt() {
+        for p in [3u32, 13, 23, 41] {
+            let modulus = BigUint::from(p);
+            for a in 0..2 * p {
+                let a = BigUint::from(a);
+                assert_eq!(
+                    FiniteField::is_quadratic_residue(&a, &modulus),
+                    FiniteField::sqrt(&a, &modulus).is_some(),
+                    "a = {}, p = {}",
+                    a,
+                    p
+                );
+            }
+        }
+        assert_eq!(
+            FiniteField::legendre_symbol(&BigUint::from(5u32), &BigUint::from(23u32)),
+            -1
+        );
+    }
+
+    #[test]
+    fn test_inv_mul_fermat_matches_inv_mul() {
+        for p in [5u32, 19, 101] {
+            let p = BigUint::from(p);
+            for a in 1u32..200 {
+                let a = BigUint::from(a);
+                if (&a % &p).is_zero() {
+                    continue;
+                }
+                assert_eq!(
+                    FiniteField::inv_mul_fermat(&a, &p),
+                    FiniteField::inv_mul(&a, &p)
+                );
+            }
+        }
+
+        let n = BigUint::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+            16,
+        )
+        .unwrap();
+        let a = BigUint::from(0xC0FFEEu32);
+        assert_eq!(
+            FiniteField::inv_mul_fermat(&a, &n),
+            FiniteField::inv_mul(&a, &n)
+        );
+        assert!(FiniteField::inv_mul_fermat(&BigUint::zero(), &n).is_zero());
+    }
+
+    #[test]
+    fn test_add_matches_reduction() {
+        let mut rng = rand::thread_rng();
+        let p = BigUint::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+            16,
+        )
+        .unwrap();
+        let expected = |a: &BigUint, b: &BigUint| (a + b) % &p;
+
+        // no reduction, one subtraction, and sums landing exactly on p and 2p
+        let edge = [
+            (BigUint::zero(), BigUint::zero()),
+            (BigUint::one(), &p - 2u32),
+            (BigUint::one(), &p - 1u32),
+            (&p - 1u32, &p - 1u32),
+            (p.clone(), p.clone()),
+            (&p * 3u32 + 5u32, BigUint::from(7u32)),
+        ];
+        for (a, b) in &edge {
+            assert_eq!(FiniteField::add(a, b, &p), expected(a, b));
+        }
+
+        for _ in 0..200 {
+            let a = rng.gen_biguint_below(&p);
+            let b = rng.gen_biguint_below(&p);
+            assert_eq!(FiniteField::add(&a, &b, &p), expected(&a, &b));
+            // unreduced inputs still take the division
+            let c = rng.gen_biguint(300);
+            assert_eq!(FiniteField::add(&a, &c, &p), expected(&a, &c));
+        }
+    }
+
+    #[test]
+    fn test_inverse_table() {
+        for p in [2u32, 3, 17, 101, 65537] {
+            let p = BigUint::from(p);
+            let table = FiniteField::inverse_table(&p);
+            assert_eq!(BigUint::from(table.len()), p);
+            assert!(table[0].is_zero());
+            for (i, inverse) in table.iter().enumerate().skip(1) {
+                assert!(FiniteField::mul(&BigUint::from(i), inverse, &p).is_one());
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "limited to moduli up to 2^20")]
+    fn test_inverse_table_rejects_large_moduli() {
+        FiniteField::inverse_table(&BigUint::from((1u32 << 20) + 7));
+    }
+
+    #[test]
+    fn test_poly_eval() {
+        // 3x^2 + 2x + 1 at x = 2 is 17 = 6 mod 11
+        let p = BigUint::from(11u32);
+        let coeffs = [1u32, 2, 3].map(BigUint::from);
+        assert_eq!(
+            FiniteField::poly_eval(&coeffs, &BigUint::from(2u32), &p),
+            BigUint::from(6u32)
+        );
+        assert_eq!(
+            FiniteField::poly_eval(&coeffs, &BigUint::zero(), &p),
+            BigUint::one()
+        );
+        assert!(FiniteField::poly_eval(&[], &BigUint::from(2u32), &p).is_zero());
+    }
+
+    #[test]
+    fn test_lagrange_interpolate() {
+        // f(x) = 5x^3 + 2x^2 + 7x + 13 mod 101
+        let p = BigUint::from(101u32);
+        let f = |x: u32| BigUint::from((5 * x * x * x + 2 * x * x + 7 * x + 13) % 101);
+        let points: Vec<_> = [3u32, 8, 20, 42]
+            .iter()
+            .map(|&x| (BigUint::from(x), f(x)))
+            .collect();
+
+        assert_eq!(
+            FiniteField::lagrange_interpolate(&points, &BigUint::zero(), &p),
+            BigUint::from(13u32)
+        );
+        assert_eq!(
+            FiniteField::lagrange_interpolate(&points, &BigUint::from(5u32), &p),
+            f(5)
+        );
+        assert_eq!(
+            FiniteField::lagrange_interpolate(&points[..1], &BigUint::zero(), &p),
+            f(3)
+        );
+    }
 }
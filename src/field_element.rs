@@ -0,0 +1,183 @@
+use crate::ff::FiniteField;
+use alloc::rc::Rc;
+use core::ops::{Add, Div, Mul, Sub};
+use num_bigint::BigUint;
+
+/// An element of `F_p` that carries its modulus, so arithmetic reads as
+/// `&a * &b + c` instead of threading `p` through every `FiniteField` call.
+///
+/// Elements share the modulus through an `Rc`. Combining elements with
+/// different moduli panics.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldElement {
+    value: BigUint,
+    modulus: Rc<BigUint>,
+}
+
+impl FieldElement {
+    /// Creates an element, reducing `value` modulo `modulus`
+    pub fn new(value: BigUint, modulus: Rc<BigUint>) -> Self {
+        let value = value % modulus.as_ref();
+        FieldElement { value, modulus }
+    }
+
+    /// Creates another element of the same field
+    pub fn with_value(&self, value: BigUint) -> Self {
+        Self::new(value, Rc::clone(&self.modulus))
+    }
+
+    pub fn value(&self) -> &BigUint {
+        &self.value
+    }
+
+    pub fn modulus(&self) -> &BigUint {
+        &self.modulus
+    }
+
+    /// Multiplicative inverse; panics for zero
+    pub fn inv(&self) -> Self {
+        self.with_value(FiniteField::inv_mul(&self.value, &self.modulus))
+    }
+
+    /// A square root, or `None` for a quadratic non-residue
+    pub fn sqrt(&self) -> Option<Self> {
+        FiniteField::sqrt(&self.value, &self.modulus).map(|root| self.with_value(root))
+    }
+
+    pub fn pow(&self, exponent: &BigUint) -> Self {
+        self.with_value(FiniteField::exp(&self.value, exponent, &self.modulus))
+    }
+
+    fn check_modulus(&self, other: &Self) {
+        assert!(
+            Rc::ptr_eq(&self.modulus, &other.modulus) || self.modulus == other.modulus,
+            "FieldElement moduli differ"
+        );
+    }
+}
+
+impl Add for &FieldElement {
+    type Output = FieldElement;
+
+    fn add(self, rhs: &FieldElement) -> FieldElement {
+        self.check_modulus(rhs);
+        self.with_value(FiniteField::add(&self.value, &rhs.value, &self.modulus))
+    }
+}
+
+impl Sub for &FieldElement {
+    type Output = FieldElement;
+
+    fn sub(self, rhs: &FieldElement) -> FieldElement {
+        self.check_modulus(rhs);
+        self.with_value(FiniteField::sub(&self.value, &rhs.value, &self.modulus))
+    }
+}
+
+impl Mul for &FieldElement {
+    type Output = FieldElement;
+
+    fn mul(self, rhs: &FieldElement) -> FieldElement {
+        self.check_modulus(rhs);
+        self.with_value(FiniteField::mul(&self.value, &rhs.value, &self.modulus))
+    }
+}
+
+impl Div for &FieldElement {
+    type Output = FieldElement;
+
+    fn div(self, rhs: &FieldElement) -> FieldElement {
+        self.check_modulus(rhs);
+        self.with_value(FiniteField::div(&self.value, &rhs.value, &self.modulus))
+    }
+}
+
+impl Add for FieldElement {
+    type Output = FieldElement;
+
+    fn add(self, rhs: FieldElement) -> FieldElement {
+        &self + &rhs
+    }
+}
+
+impl Sub for FieldElement {
+    type Output = FieldElement;
+
+    fn sub(self, rhs: FieldElement) -> FieldElement {
+        &self - &rhs
+    }
+}
+
+impl Mul for FieldElement {
+    type Output = FieldElement;
+
+    fn mul(self, rhs: FieldElement) -> FieldElement {
+        &self * &rhs
+    }
+}
+
+impl Div for FieldElement {
+    type Output = FieldElement;
+
+    fn div(self, rhs: FieldElement) -> FieldElement {
+        &self / &rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup() -> (FieldElement, FieldElement, BigUint) {
+        let p = BigUint::from(23u32);
+        let modulus = Rc::new(p.clone());
+        (
+            FieldElement::new(BigUint::from(7u32), Rc::clone(&modulus)),
+            FieldElement::new(BigUint::from(36u32), modulus),
+            p,
+        )
+    }
+
+    #[test]
+    fn test_matches_finite_field() {
+        let (a, b, p) = setup();
+        let (x, y) = (a.value().clone(), b.value().clone());
+        assert_eq!(y, BigUint::from(13u32));
+
+        assert_eq!((&a + &b).value(), &FiniteField::add(&x, &y, &p));
+        assert_eq!((&a - &b).value(), &FiniteField::sub(&x, &y, &p));
+        assert_eq!((&a * &b).value(), &FiniteField::mul(&x, &y, &p));
+        assert_eq!((&a / &b).value(), &FiniteField::div(&x, &y, &p));
+        assert_eq!(a.inv().value(), &FiniteField::inv_mul(&x, &p));
+        assert_eq!(
+            a.pow(&BigUint::from(5u32)).value(),
+            &FiniteField::exp(&x, &BigUint::from(5u32), &p)
+        );
+        assert_eq!(a.clone() * a.inv(), a.with_value(BigUint::from(1u32)));
+    }
+
+    #[test]
+    fn test_sqrt() {
+        let (a, _, _) = setup();
+        let square = &a * &a;
+        let root = square.sqrt().unwrap();
+        assert_eq!(&root * &root, square);
+        // 5 is a non-residue mod 23
+        assert_eq!(a.with_value(BigUint::from(5u32)).sqrt(), None);
+    }
+
+    #[test]
+    fn test_equal_moduli_in_separate_allocations_mix() {
+        let (a, _, p) = setup();
+        let b = FieldElement::new(BigUint::from(2u32), Rc::new(p));
+        assert_eq!((a + b).value(), &BigUint::from(9u32));
+    }
+
+    #[test]
+    #[should_panic(expected = "FieldElement moduli differ")]
+    fn test_mixing_moduli_panics() {
+        let (a, _, _) = setup();
+        let b = FieldElement::new(BigUint::from(2u32), Rc::new(BigUint::from(29u32)));
+        let _ = &a * &b;
+    }
+}
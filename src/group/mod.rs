@@ -0,0 +1,345 @@
+use crate::ec::EllipticCurve;
+use crate::ff::FiniteField;
+use crate::point::Point;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use num_bigint::BigUint;
+use num_traits::{One, ToPrimitive, Zero};
+
+pub mod pedersen;
+
+/// Window width in bits of the precomputed powers of `g`
+const G_TABLE_WINDOW: u64 = 4;
+
+/// Starting points `discrete_log_rho` tries before giving up
+const RHO_ATTEMPTS: u32 = 8;
+
+/// A cyclic group of prime order in which discrete logarithms are hard, written
+/// additively or multiplicatively depending on the implementation
+pub trait DlogGroup {
+  type Element: Clone + PartialEq + Debug;
+
+  fn generator(&self) -> Self::Element;
+  fn combine(&self, a: &Self::Element, b: &Self::Element) -> Self::Element;
+  fn scalar_mul(&self, e: &Self::Element, s: &BigUint) -> Self::Element;
+  fn order(&self) -> &BigUint;
+
+  /// Like `scalar_mul`, for secret scalars. Groups with a constant-time
+  /// implementation override this.
+  fn scalar_mul_secret(&self, e: &Self::Element, s: &BigUint) -> Self::Element {
+    self.scalar_mul(e, s)
+  }
+}
+
+pub struct Group {
+  pub p: BigUint,
+  pub q: BigUint,
+  pub g: BigUint,
+  pub h: BigUint,
+  g_table: Option<Vec<Vec<BigUint>>>,
+}
+
+impl Group {
+  pub fn new(p: BigUint, q: BigUint, g: BigUint, h: BigUint) -> Self {
+    Self { p, q, g, h, g_table: None }
+  }
+
+  /// Precomputes powers of `g` for `exp_g`.
+  ///
+  /// Exponents are reduced modulo p - 1 and split into 4-bit windows; for
+  /// window `i` the table holds `g^(j·2^(4i))` for every nonzero digit `j`, so
+  /// `exp_g` needs one multiplication per window and no squarings.
+  pub fn precompute_g(mut self) -> Self {
+    let windows = (&self.p - 1u32).bits().div_ceil(G_TABLE_WINDOW);
+    let mut table = Vec::with_capacity(windows as usize);
+    let mut window_base = &self.g % &self.p;
+
+    for _ in 0..windows {
+      let mut row = Vec::with_capacity((1 << G_TABLE_WINDOW) - 1);
+      let mut power = window_base.clone();
+      for _ in 1..(1 << G_TABLE_WINDOW) {
+        row.push(power.clone());
+        power = FiniteField::mul(&power, &window_base, &self.p);
+      }
+      // after the inner loop `power` is the window base raised to 2^4
+      window_base = power;
+      table.push(row);
+    }
+
+    self.g_table = Some(table);
+    self
+  }
+
+  /// Computes `g^exponent mod p`, using the table from `precompute_g` if built.
+  ///
+  /// Table lookups are indexed by the exponent's digits, so prefer
+  /// `FiniteField::exp_ct` for secret exponents.
+  pub fn exp_g(&self, exponent: &BigUint) -> BigUint {
+    let table = match &self.g_table {
+      Some(table) => table,
+      None => return FiniteField::exp(&self.g, exponent, &self.p),
+    };
+
+    // g^(p-1) = 1 by Fermat's little theorem
+    let exponent = exponent % (&self.p - 1u32);
+    let mut result = BigUint::one() % &self.p;
+    for (i, window) in table.iter().enumerate() {
+      let digit = (0..G_TABLE_WINDOW).fold(0usize, |acc, bit| {
+        let set = exponent.bit(i as u64 * G_TABLE_WINDOW + bit);
+        acc | (usize::from(set) << bit)
+      });
+      if digit != 0 {
+        result = FiniteField::mul(&result, &window[digit - 1], &self.p);
+      }
+    }
+
+    result
+  }
+
+  /// Solves `target = base^x mod p` for `x <= max` with baby-step giant-step,
+  /// or returns `None` if no such `x` exists.
+  ///
+  /// Stores `m = ⌈√(max + 1)⌉` baby steps `base^j` and then walks
+  /// `target · base^(-i·m)`, so both time and memory are `O(√max)`.
+  pub fn discrete_log(&self, base: &BigUint, target: &BigUint, max: &BigUint) -> Option<BigUint> {
+    let m = max.sqrt() + 1u32;
+
+    let mut baby_steps = BTreeMap::new();
+    let mut step = BigUint::one() % &self.p;
+    let mut j = BigUint::zero();
+    while j < m {
+      baby_steps.entry(step.clone()).or_insert_with(|| j.clone());
+      step = FiniteField::mul(&step, base, &self.p);
+      j += 1u32;
+    }
+
+    // `step` is now base^m
+    let giant_step = FiniteField::inv_mul(&step, &self.p);
+    let mut gamma = target % &self.p;
+    let mut i = BigUint::zero();
+    while i < m {
+      if let Some(j) = baby_steps.get(&gamma) {
+        let x = &i * &m + j;
+        return (&x <= max).then_some(x);
+      }
+      gamma = FiniteField::mul(&gamma, &giant_step, &self.p);
+      i += 1u32;
+    }
+    None
+  }
+
+  /// Solves `target = base^x mod p` with Pollard's rho, for `base` of prime
+  /// order `q`. Returns `None` if no walk finds the logarithm, e.g. when
+  /// `target` is outside the subgroup.
+  ///
+  /// Each walk starts at `base^a0 · target` and tracks `y = base^a · target^b`,
+  /// stepping by `y·target`, `y^2` or `y·base` depending on `y mod 3`. Floyd's
+  /// cycle detection finds a collision `base^a1·target^b1 = base^a2·target^b2`
+  /// in `O(√q)` steps and constant memory, giving `x = (a2 - a1)/(b1 - b2)`.
+  pub fn discrete_log_rho(&self, base: &BigUint, target: &BigUint) -> Option<BigUint> {
+    let target = target % &self.p;
+
+    for a0 in 1u32..=RHO_ATTEMPTS {
+      let a0 = BigUint::from(a0);
+      let y0 = FiniteField::mul(&FiniteField::exp(base, &a0, &self.p), &target, &self.p);
+      let start = (y0, a0 % &self.q, BigUint::one() % &self.q);
+      let mut tortoise = self.rho_step(&start, base, &target);
+      let mut hare = self.rho_step(&tortoise, base, &target);
+      while tortoise.0 != hare.0 {
+        tortoise = self.rho_step(&tortoise, base, &target);
+        hare = self.rho_step(&self.rho_step(&hare, base, &target), base, &target);
+      }
+
+      let (_, a1, b1) = tortoise;
+      let (_, a2, b2) = hare;
+      let denominator = FiniteField::sub(&b1, &b2, &self.q);
+      if denominator.is_zero() {
+        continue;
+      }
+      let x = FiniteField::div(&FiniteField::sub(&a2, &a1, &self.q), &denominator, &self.q);
+      if FiniteField::exp(base, &x, &self.p) == target {
+        return Some(x);
+      }
+    }
+    None
+  }
+
+  /// One step of the rho walk on `(y, a, b)` with `y = base^a · target^b`
+  fn rho_step(
+    &self,
+    (y, a, b): &(BigUint, BigUint, BigUint),
+    base: &BigUint,
+    target: &BigUint,
+  ) -> (BigUint, BigUint, BigUint) {
+    match (y % 3u32).to_u32() {
+      Some(0) => (
+        FiniteField::mul(y, target, &self.p),
+        a.clone(),
+        FiniteField::add(b, &BigUint::one(), &self.q),
+      ),
+      Some(1) => (
+        FiniteField::mul(y, y, &self.p),
+        FiniteField::add(a, a, &self.q),
+        FiniteField::add(b, b, &self.q),
+      ),
+      _ => (
+        FiniteField::mul(y, base, &self.p),
+        FiniteField::add(a, &BigUint::one(), &self.q),
+        b.clone(),
+      ),
+    }
+  }
+}
+
+/// The order-q subgroup of Z_p* generated by `g`
+impl DlogGroup for Group {
+  type Element = BigUint;
+
+  fn generator(&self) -> BigUint {
+    self.g.clone()
+  }
+
+  fn combine(&self, a: &BigUint, b: &BigUint) -> BigUint {
+    FiniteField::mul(a, b, &self.p)
+  }
+
+  fn scalar_mul(&self, e: &BigUint, s: &BigUint) -> BigUint {
+    FiniteField::exp(e, s, &self.p)
+  }
+
+  fn order(&self) -> &BigUint {
+    &self.q
+  }
+
+  fn scalar_mul_secret(&self, e: &BigUint, s: &BigUint) -> BigUint {
+    FiniteField::exp_ct(e, s, &self.p)
+  }
+}
+
+/// The prime-order subgroup of a curve generated by its base point
+impl<T: EllipticCurve> DlogGroup for T {
+  type Element = Point;
+
+  fn generator(&self) -> Point {
+    self.base_point().clone()
+  }
+
+  fn combine(&self, a: &Point, b: &Point) -> Point {
+    self.add(a, b)
+  }
+
+  fn scalar_mul(&self, e: &Point, s: &BigUint) -> Point {
+    self.mul(e, s)
+  }
+
+  fn order(&self) -> &BigUint {
+    EllipticCurve::order(self)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use num_bigint::RandBigInt;
+
+  #[test]
+  fn test_exp_g_matches_exp() {
+    let mut rng = rand::thread_rng();
+    let toy = Group::new(
+      BigUint::from(23u32),
+      BigUint::from(11u32),
+      BigUint::from(4u32),
+      BigUint::from(9u32),
+    );
+    let p = BigUint::parse_bytes(
+      b"B10B8F96A080E01DDE92DE5EAE5D54EC52C99FBCFB06A3C69A6A9DCA52D23B616073E28675A23D189838EF1E2EE652C013ECB4AEA906112324975C3CD49B83BFACCBDD7D90C4BD7098488E9C219A73724EFFD6FAE5644738FAA31A4FF55BCCC0A151AF5F0DC8B4BD45BF37DF365C1A65E68CFDA76D4DA708DF1FB2BC2E4A4371",
+      16,
+    )
+    .unwrap();
+    let g = BigUint::parse_bytes(
+      b"A4D1CBD5C3FD34126765A442EFB99905F8104DD258AC507FD6406CFF14266D31266FEA1E5C41564B777E690F5504F213160217B4B01B886A5E91547F9E2749F4D7FBD7D3B9A92EE1909D0D2263F80A76A6A24C087A091F531DBF0A0169B6A28AD662A4D18E73AFA32D779D5918D08BC8858F4DCEF97C2A24855E6EEB22B3B2E5",
+      16,
+    )
+    .unwrap();
+    let q = BigUint::parse_bytes(b"F518AA8781A8DF278ABA4E7D64B7CB9D49462353", 16).unwrap();
+    let h = FiniteField::exp(&g, &BigUint::from(2u32), &p);
+    let rfc5114 = Group::new(p, q, g, h);
+
+    for group in [toy, rfc5114] {
+      let group = group.precompute_g();
+      let mut exponents = vec![
+        BigUint::from(0u32),
+        BigUint::from(1u32),
+        &group.p - 1u32,
+        &group.p + 5u32,
+      ];
+      for _ in 0..5 {
+        exponents.push(rng.gen_biguint_below(&group.p));
+      }
+
+      for e in &exponents {
+        assert_eq!(group.exp_g(e), FiniteField::exp(&group.g, e, &group.p));
+      }
+    }
+  }
+
+  #[test]
+  fn test_discrete_log() {
+    let toy = Group::new(
+      BigUint::from(23u32),
+      BigUint::from(11u32),
+      BigUint::from(4u32),
+      BigUint::from(9u32),
+    );
+    for x in 0u32..11 {
+      let target = FiniteField::exp(&toy.g, &BigUint::from(x), &toy.p);
+      assert_eq!(
+        toy.discrete_log(&toy.g, &target, &BigUint::from(10u32)),
+        Some(BigUint::from(x))
+      );
+    }
+    // 5 is not in the subgroup generated by 4
+    assert_eq!(toy.discrete_log(&toy.g, &BigUint::from(5u32), &toy.q), None);
+    // h = 9 = 4^8
+    assert_eq!(toy.discrete_log(&toy.g, &toy.h, &toy.q), Some(BigUint::from(8u32)));
+  }
+
+  mod discrete_log_rho {
+    use super::*;
+
+    #[test]
+    fn test_toy_group() {
+      let toy = Group::new(
+        BigUint::from(23u32),
+        BigUint::from(11u32),
+        BigUint::from(4u32),
+        BigUint::from(9u32),
+      );
+      for x in 0u32..11 {
+        let target = FiniteField::exp(&toy.g, &BigUint::from(x), &toy.p);
+        assert_eq!(toy.discrete_log_rho(&toy.g, &target), Some(BigUint::from(x)));
+      }
+      // 5 is not in the subgroup generated by 4
+      assert_eq!(toy.discrete_log_rho(&toy.g, &BigUint::from(5u32)), None);
+    }
+
+    #[test]
+    fn test_moderate_subgroup() {
+      // Rho needs about √q steps whatever the size of the exponent, so the
+      // 160-bit RFC 5114 subgroup is out of reach. This uses a 33-bit prime q
+      // dividing p - 1 for a 64-bit p instead.
+      let group = Group::new(
+        BigUint::from(0x8000000f80000079u64),
+        BigUint::from(0x10000000fu64),
+        BigUint::from(0x7e9a7f1df4574cccu64),
+        BigUint::one(),
+      );
+      assert!(FiniteField::exp(&group.g, &group.q, &group.p).is_one());
+
+      let x = BigUint::from(0xC0FFEE42u32);
+      let target = FiniteField::exp(&group.g, &x, &group.p);
+      assert_eq!(group.discrete_log_rho(&group.g, &target), Some(x));
+    }
+  }
+}
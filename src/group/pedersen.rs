@@ -0,0 +1,76 @@
+use crate::ff::FiniteField;
+use crate::group::Group;
+use num_bigint::BigUint;
+
+/// Commits to `value` as `g^value · h^blinding mod p`.
+///
+/// The commitment hides `value` as long as `blinding` is random, and binds the
+/// committer as long as nobody knows the discrete log of `h` to base `g`. Both
+/// exponents are secret, so they go through `FiniteField::exp_ct`.
+pub fn commit(group: &Group, value: &BigUint, blinding: &BigUint) -> BigUint {
+    let g_v = FiniteField::exp_ct(&group.g, value, &group.p);
+    let h_r = FiniteField::exp_ct(&group.h, blinding, &group.p);
+    FiniteField::mul(&g_v, &h_r, &group.p)
+}
+
+/// Checks that `commitment` opens to `value` with `blinding`
+pub fn verify_opening(
+    group: &Group,
+    commitment: &BigUint,
+    value: &BigUint,
+    blinding: &BigUint,
+) -> bool {
+    FiniteField::ct_eq(&commit(group, value, blinding), commitment, &group.p)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_group() -> Group {
+        Group::new(
+            BigUint::from(23u32),
+            BigUint::from(11u32),
+            BigUint::from(4u32),
+            BigUint::from(9u32),
+        )
+    }
+
+    #[test]
+    fn test_opening() {
+        let group = create_test_group();
+        let (value, blinding) = (BigUint::from(7u32), BigUint::from(3u32));
+        let commitment = commit(&group, &value, &blinding);
+
+        assert!(verify_opening(&group, &commitment, &value, &blinding));
+        assert!(!verify_opening(
+            &group,
+            &commitment,
+            &BigUint::from(8u32),
+            &blinding
+        ));
+        assert!(!verify_opening(
+            &group,
+            &commitment,
+            &value,
+            &BigUint::from(4u32)
+        ));
+    }
+
+    #[test]
+    fn test_commitments_are_homomorphic() {
+        let group = create_test_group();
+        for (a, r1, b, r2) in [(1u32, 2u32, 3u32, 4u32), (7, 10, 9, 8), (0, 0, 10, 10)] {
+            let (a, r1, b, r2) = (
+                BigUint::from(a),
+                BigUint::from(r1),
+                BigUint::from(b),
+                BigUint::from(r2),
+            );
+            let product =
+                FiniteField::mul(&commit(&group, &a, &r1), &commit(&group, &b, &r2), &group.p);
+
+            assert_eq!(product, commit(&group, &(&a + &b), &(&r1 + &r2)));
+        }
+    }
+}
@@ -1,16 +1,48 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 mod ec;
 mod ff;
+mod field_element;
 mod point;
 mod curves;
 mod ecdsa;
 mod zk;
 mod group;
+mod error;
+mod encoding;
+mod hmac;
+mod private_key;
+pub mod sss;
+mod u256;
+mod montgomery_field;
 
 
-pub use ec::{EllipticCurve, WeierstrassCurve};
-pub use ff::FiniteField;
+#[cfg(feature = "serde")]
+pub use ec::CurveParams;
+pub use ec::{
+    AddKind, CurvePoint, EdwardsCurve, EllipticCurve, Endomorphism, MontgomeryCurve,
+    WeierstrassCurve,
+};
+pub use ff::{BigUintField, FiniteField, PrimeField, ScalarField};
+pub use field_element::FieldElement;
 pub use point::Point;
+pub use curves::{by_name, from_oid, lookup_by_name};
+pub use curves::curve25519::create_curve25519;
+pub use curves::ed25519::create_ed25519;
 pub use curves::secp256k1::create_secp256k1_weierstrass;
-pub use ecdsa::ECDSA;
-pub use group::Group;
-pub use zk::chaum_pedersen::ChaumPedersen;
+pub use curves::secp256k1_fast::Secp256k1Fast;
+pub use curves::secp256r1::create_secp256r1_weierstrass;
+pub use ecdsa::{bits2int, quick_verify, ECDSA};
+pub use error::{EccError, Error};
+pub use private_key::PrivateKey;
+pub use group::{pedersen, DlogGroup, Group};
+pub use zk::chaum_pedersen::{self, ChaumPedersen};
+pub use zk::dlog;
+pub use zk::transcript::Transcript;
+pub use zk::vrf;
+
+#[cfg(doctest)]
+#[doc = include_str!("../README.md")]
+struct ReadmeDoctests;
@@ -0,0 +1,153 @@
+use crate::ff::FiniteField;
+use num_bigint::BigUint;
+use num_traits::One;
+
+/// Arithmetic modulo an odd `p` on values kept in Montgomery form `a·R mod p`.
+///
+/// `R` is the smallest power of `2^64` above `p`. A product of two values in
+/// Montgomery form is brought back into range with Montgomery reduction
+/// (REDC), which needs only multiplications, masks and shifts by `R` instead
+/// of the division behind `% p`. Converting in and out costs one reduction
+/// each, so this pays off over long runs of multiplications such as a scalar
+/// multiplication.
+pub(crate) struct MontgomeryField {
+    p: BigUint,
+    r_bits: u64,
+    /// `R - 1`, for reducing modulo `R`
+    mask: BigUint,
+    /// `-p^-1 mod R`
+    n_prime: BigUint,
+    /// `R^2 mod p`, for converting into Montgomery form
+    r2: BigUint,
+}
+
+impl MontgomeryField {
+    /// Panics unless `p` is odd and above 1
+    pub(crate) fn new(p: &BigUint) -> Self {
+        assert!(
+            p.bit(0) && !p.is_one(),
+            "Montgomery form needs an odd modulus above 1"
+        );
+        let r_bits = p.bits().div_ceil(64) * 64;
+        let r = BigUint::one() << r_bits;
+        let p_inv = FiniteField::inv_mul(&(p % &r), &r);
+        MontgomeryField {
+            p: p.clone(),
+            r_bits,
+            mask: &r - 1u32,
+            n_prime: &r - p_inv,
+            r2: (&r * &r) % p,
+        }
+    }
+
+    /// Converts `a` into Montgomery form
+    pub(crate) fn encode(&self, a: &BigUint) -> BigUint {
+        self.redc(&((a % &self.p) * &self.r2))
+    }
+
+    /// Converts `a` out of Montgomery form
+    pub(crate) fn decode(&self, a: &BigUint) -> BigUint {
+        self.redc(a)
+    }
+
+    /// Computes `t·R^-1 mod p` for `t < p·R`
+    fn redc(&self, t: &BigUint) -> BigUint {
+        let m = ((t & &self.mask) * &self.n_prime) & &self.mask;
+        let u = (t + m * &self.p) >> self.r_bits;
+        if u >= self.p {
+            u - &self.p
+        } else {
+            u
+        }
+    }
+
+    pub(crate) fn add(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        let sum = a + b;
+        if sum >= self.p {
+            sum - &self.p
+        } else {
+            sum
+        }
+    }
+
+    pub(crate) fn sub(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        if a >= b {
+            a - b
+        } else {
+            &self.p - b + a
+        }
+    }
+
+    pub(crate) fn mul(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        self.redc(&(a * b))
+    }
+
+    pub(crate) fn square(&self, a: &BigUint) -> BigUint {
+        self.mul(a, a)
+    }
+
+    /// Multiplies by a small constant with repeated additions
+    pub(crate) fn mul_small(&self, a: &BigUint, k: u32) -> BigUint {
+        (1..k).fold(a.clone(), |acc, _| self.add(&acc, a))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curves::secp256k1::create_secp256k1_weierstrass;
+    use crate::curves::secp256r1::create_secp256r1_weierstrass;
+    use crate::ec::EllipticCurve;
+    use num_bigint::RandBigInt;
+    use num_traits::Zero;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_arithmetic_matches_finite_field() {
+        let mut rng = StdRng::seed_from_u64(92);
+        let moduli = [
+            BigUint::from(17u32),
+            BigUint::from(u64::MAX - 58), // the largest 64-bit prime
+            create_secp256k1_weierstrass().field_modulus().clone(),
+            create_secp256r1_weierstrass().field_modulus().clone(),
+        ];
+
+        for p in &moduli {
+            let field = MontgomeryField::new(p);
+            let mut values = vec![BigUint::zero(), BigUint::one(), p - 1u32];
+            values.extend((0..10).map(|_| rng.gen_biguint_below(p)));
+
+            for a in &values {
+                let ma = field.encode(a);
+                assert!(&ma < p);
+                assert_eq!(field.decode(&ma), *a);
+                assert_eq!(
+                    field.decode(&field.mul_small(&ma, 8)),
+                    FiniteField::mul(a, &BigUint::from(8u32), p)
+                );
+                for b in &values {
+                    let mb = field.encode(b);
+                    assert_eq!(
+                        field.decode(&field.mul(&ma, &mb)),
+                        FiniteField::mul(a, b, p)
+                    );
+                    assert_eq!(
+                        field.decode(&field.add(&ma, &mb)),
+                        FiniteField::add(a, b, p)
+                    );
+                    assert_eq!(
+                        field.decode(&field.sub(&ma, &mb)),
+                        FiniteField::sub(a, b, p)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "odd modulus")]
+    fn test_rejects_even_modulus() {
+        MontgomeryField::new(&BigUint::from(16u32));
+    }
+}
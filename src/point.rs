@@ -1,8 +1,208 @@
+use crate::ec::EllipticCurve;
+use crate::encoding::{byte_length, to_bytes_be_padded};
+use crate::error::Error;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
 use num_bigint::BigUint;
 
 /// Represents a point on an elliptic curve
-#[derive(PartialEq, Clone, Debug)]
+///
+/// Equality and hashing compare coordinates as stored, so two encodings of
+/// the same point only match when both are reduced modulo the field prime.
+/// Points returned by curve arithmetic are always reduced; build others with
+/// `WeierstrassCurve::point`.
+#[derive(PartialEq, Eq, Clone, Debug)]
 pub enum Point {
     Coordinates(BigUint, BigUint),
     Identity,
-}
\ No newline at end of file
+}
+
+impl Hash for Point {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Point::Coordinates(x, y) => {
+                state.write_u8(0);
+                x.to_bytes_be().hash(state);
+                y.to_bytes_be().hash(state);
+            }
+            Point::Identity => state.write_u8(1),
+        }
+    }
+}
+
+impl Point {
+    /// Returns true for the point at infinity
+    pub fn is_identity(&self) -> bool {
+        matches!(self, Point::Identity)
+    }
+
+    /// Returns the affine coordinates, or `None` for the point at infinity
+    pub fn coordinates(&self) -> Option<(&BigUint, &BigUint)> {
+        match self {
+            Point::Coordinates(x, y) => Some((x, y)),
+            Point::Identity => None,
+        }
+    }
+
+    /// Builds a point from hex coordinates, written the way curve parameters are
+    /// in `curves::secp256k1` (no prefix, either case)
+    pub fn from_hex(x_hex: &str, y_hex: &str) -> Result<Point, Error> {
+        let parse = |hex: &str| BigUint::parse_bytes(hex.as_bytes(), 16).ok_or(Error::InvalidHex);
+        Ok(Point::Coordinates(parse(x_hex)?, parse(y_hex)?))
+    }
+
+    /// Returns the coordinates as upper-case hex, or `None` for the point at infinity
+    pub fn to_hex(&self) -> Option<(String, String)> {
+        self.coordinates()
+            .map(|(x, y)| (format!("{:X}", x), format!("{:X}", y)))
+    }
+
+    /// Checks the point against the equation of `curve`
+    pub fn is_on_curve<C: EllipticCurve>(&self, curve: &C) -> bool {
+        curve.is_on_curve(self)
+    }
+
+    /// Returns `a` when `choice` is false and `b` when it is true, without
+    /// branching on `choice`.
+    ///
+    /// Both points are encoded as an identity flag followed by coordinates
+    /// padded to the byte length of the field modulus `p`, so the work depends
+    /// only on the field, and combined byte by byte under a mask derived from
+    /// `choice`. The coordinates of the result are always decoded; the flag
+    /// then indexes the finished candidates instead of guarding a branch.
+    /// Both points must have coordinates reduced modulo `p`.
+    pub fn conditional_select(a: &Point, b: &Point, choice: bool, p: &BigUint) -> Point {
+        let width = byte_length(p);
+        let mask = 0u8.wrapping_sub(choice as u8);
+        let selected: Vec<u8> = a
+            .to_fixed_bytes(width)
+            .iter()
+            .zip(b.to_fixed_bytes(width))
+            .map(|(a, b)| a ^ (mask & (a ^ b)))
+            .collect();
+
+        let x = BigUint::from_bytes_be(&selected[1..=width]);
+        let y = BigUint::from_bytes_be(&selected[width + 1..]);
+        let mut candidates = [Point::Coordinates(x, y), Point::Identity];
+        core::mem::replace(
+            &mut candidates[usize::from(selected[0] & 1)],
+            Point::Identity,
+        )
+    }
+
+    /// `flag || x || y` with each coordinate padded to `width` bytes; the
+    /// identity has flag 1 and zero coordinates
+    fn to_fixed_bytes(&self, width: usize) -> Vec<u8> {
+        let zero = BigUint::default();
+        let (flag, x, y) = match self {
+            Point::Coordinates(x, y) => (0, x, y),
+            Point::Identity => (1, &zero, &zero),
+        };
+        let mut bytes = Vec::with_capacity(1 + 2 * width);
+        bytes.push(flag);
+        bytes.extend(to_bytes_be_padded(x, width));
+        bytes.extend(to_bytes_be_padded(y, width));
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curves::secp256k1::create_secp256k1_weierstrass;
+
+    #[test]
+    fn test_is_identity() {
+        assert!(Point::Identity.is_identity());
+        assert!(!Point::Coordinates(BigUint::from(1u32), BigUint::from(2u32)).is_identity());
+    }
+
+    #[test]
+    fn test_coordinates() {
+        let p = Point::Coordinates(BigUint::from(1u32), BigUint::from(2u32));
+        assert_eq!(
+            p.coordinates(),
+            Some((&BigUint::from(1u32), &BigUint::from(2u32)))
+        );
+        assert_eq!(Point::Identity.coordinates(), None);
+    }
+
+    #[test]
+    fn test_hex_round_trip() {
+        let x_hex = "79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798";
+        let y_hex = "483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8";
+        let g = Point::from_hex(x_hex, y_hex).unwrap();
+
+        assert_eq!(g, *create_secp256k1_weierstrass().base_point());
+        assert_eq!(g.to_hex(), Some((x_hex.into(), y_hex.into())));
+        assert_eq!(Point::from_hex(&x_hex.to_lowercase(), y_hex), Ok(g));
+        assert_eq!(Point::Identity.to_hex(), None);
+    }
+
+    #[test]
+    fn test_from_hex_rejects_invalid_input() {
+        assert_eq!(Point::from_hex("12", "xyz"), Err(Error::InvalidHex));
+        assert_eq!(Point::from_hex("", "12"), Err(Error::InvalidHex));
+    }
+
+    #[test]
+    fn test_is_on_curve() {
+        let curve = create_secp256k1_weierstrass();
+        let g = curve.base_point();
+        assert!(g.is_on_curve(&curve));
+        assert!(Point::Identity.is_on_curve(&curve));
+
+        let (x, y) = g.coordinates().unwrap();
+        assert!(!Point::Coordinates(x.clone(), y + 1u32).is_on_curve(&curve));
+    }
+
+    #[test]
+    fn test_conditional_select() {
+        let curve = create_secp256k1_weierstrass();
+        let g = curve.base_point();
+        let g2 = curve.double(g);
+        let small = Point::Coordinates(BigUint::from(1u32), BigUint::from(2u32));
+        let p = curve.field_modulus();
+        let select = |a: &Point, b: &Point, choice| Point::conditional_select(a, b, choice, p);
+
+        assert_eq!(select(g, &g2, false), *g);
+        assert_eq!(select(g, &g2, true), g2);
+        // coordinates of different widths
+        assert_eq!(select(&small, g, false), small);
+        assert_eq!(select(&small, g, true), *g);
+        // the identity on either side
+        assert_eq!(select(&Point::Identity, g, false), Point::Identity);
+        assert_eq!(select(&Point::Identity, g, true), *g);
+        assert_eq!(select(g, &Point::Identity, true), Point::Identity);
+        assert_eq!(
+            select(&Point::Identity, &Point::Identity, true),
+            Point::Identity
+        );
+        // every input is encoded at the width of the field
+        let width = byte_length(p);
+        for point in [g, &small, &Point::Identity] {
+            assert_eq!(point.to_fixed_bytes(width).len(), 1 + 2 * 32);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_hash_set_detects_duplicates() {
+        use std::collections::HashSet;
+
+        let curve = create_secp256k1_weierstrass();
+        let g = curve.base_point().clone();
+        let g2 = curve.double(&g);
+
+        let mut keys = HashSet::new();
+        assert!(keys.insert(g.clone()));
+        assert!(keys.insert(g2));
+        assert!(keys.insert(Point::Identity));
+        // G computed a second way is the same key
+        assert!(!keys.insert(curve.add(&curve.double(&g), &curve.negate(&g))));
+        assert!(!keys.insert(Point::Identity));
+        assert_eq!(keys.len(), 3);
+    }
+}
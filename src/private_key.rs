@@ -0,0 +1,168 @@
+use core::fmt;
+use core::ops::Deref;
+use num_bigint::BigUint;
+
+/// A private key scalar.
+///
+/// Dereferences to the underlying `BigUint`, so it can be passed anywhere a
+/// `&BigUint` scalar is expected. With the `zeroize` feature enabled the key's
+/// limbs are overwritten with zeros when it is dropped.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PrivateKey(BigUint);
+
+impl PrivateKey {
+    pub fn new(scalar: BigUint) -> Self {
+        PrivateKey(scalar)
+    }
+}
+
+impl From<BigUint> for PrivateKey {
+    fn from(scalar: BigUint) -> Self {
+        PrivateKey(scalar)
+    }
+}
+
+impl Deref for PrivateKey {
+    type Target = BigUint;
+
+    fn deref(&self) -> &BigUint {
+        &self.0
+    }
+}
+
+impl fmt::Debug for PrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PrivateKey(..)")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for PrivateKey {
+    fn zeroize(&mut self) {
+        // `BigUint` gives no mutable access to its limbs, but clearing a bit
+        // writes its limb in place. Going from the lowest bit up keeps the top
+        // limb nonzero until the last bit, so the value is not normalized (and
+        // its buffer not shrunk or reallocated) while it still holds key bits.
+        for bit in 0..self.0.bits() {
+            self.0.set_bit(bit, false);
+        }
+        core::hint::black_box(&self.0);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for PrivateKey {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for PrivateKey {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deref_to_biguint() {
+        let key = PrivateKey::new(BigUint::from(42u32));
+        assert_eq!(*key, BigUint::from(42u32));
+        assert_eq!(format!("{:?}", key), "PrivateKey(..)");
+    }
+
+    /// A global allocator that remembers the buffer allocated inside `watch`
+    /// and checks, when that buffer is freed, whether its bytes were zero
+    #[cfg(all(feature = "zeroize", feature = "std"))]
+    mod watching_allocator {
+        use std::alloc::{GlobalAlloc, Layout, System};
+        use std::cell::Cell;
+
+        std::thread_local! {
+            static ARMED: Cell<bool> = const { Cell::new(false) };
+            static WATCHED: Cell<usize> = const { Cell::new(0) };
+            static FREED_ZEROED: Cell<Option<bool>> = const { Cell::new(None) };
+        }
+
+        struct WatchingAllocator;
+
+        unsafe impl GlobalAlloc for WatchingAllocator {
+            unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+                let ptr = System.alloc(layout);
+                if ARMED.with(Cell::get) {
+                    WATCHED.with(|watched| watched.set(ptr as usize));
+                }
+                ptr
+            }
+
+            unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+                if WATCHED.with(Cell::get) == ptr as usize {
+                    WATCHED.with(|watched| watched.set(0));
+                    // SAFETY: `ptr` is a live allocation of `layout.size()` bytes
+                    // until it is handed back to `System` below
+                    let bytes = core::slice::from_raw_parts(ptr, layout.size());
+                    let zeroed = bytes.iter().all(|&byte| byte == 0);
+                    FREED_ZEROED.with(|freed| freed.set(Some(zeroed)));
+                }
+                System.dealloc(ptr, layout)
+            }
+        }
+
+        #[global_allocator]
+        static ALLOCATOR: WatchingAllocator = WatchingAllocator;
+
+        /// Runs `f`, watching the last buffer it allocates on this thread
+        pub fn watch<T>(f: impl FnOnce() -> T) -> T {
+            FREED_ZEROED.with(|freed| freed.set(None));
+            ARMED.with(|armed| armed.set(true));
+            let value = f();
+            ARMED.with(|armed| armed.set(false));
+            value
+        }
+
+        /// Whether the watched buffer held only zeros when it was freed, or
+        /// `None` while it is still allocated
+        pub fn freed_zeroed() -> Option<bool> {
+            FREED_ZEROED.with(Cell::get)
+        }
+    }
+
+    /// A key whose five 64-bit limbs are all nonzero, in a buffer of its own
+    #[cfg(all(feature = "zeroize", feature = "std"))]
+    fn watched_key() -> PrivateKey {
+        watching_allocator::watch(|| PrivateKey::new(BigUint::from_bytes_le(&[0xA5; 40])))
+    }
+
+    #[cfg(all(feature = "zeroize", feature = "std"))]
+    #[test]
+    fn test_zeroize_clears_key() {
+        use zeroize::Zeroize;
+
+        fn assert_zeroize_on_drop<T: zeroize::ZeroizeOnDrop>() {}
+        assert_zeroize_on_drop::<PrivateKey>();
+
+        let mut key = watched_key();
+        key.zeroize();
+        assert_eq!(*key, BigUint::from(0u32));
+        assert_eq!(key.to_u32_digits(), Vec::<u32>::new());
+        // clearing the last limb shrinks the buffer, which frees the old one
+        assert_eq!(watching_allocator::freed_zeroed(), Some(true));
+    }
+
+    #[cfg(all(feature = "zeroize", feature = "std"))]
+    #[test]
+    fn test_drop_clears_key() {
+        let key = watched_key();
+        assert_eq!(watching_allocator::freed_zeroed(), None);
+        drop(key);
+        assert_eq!(watching_allocator::freed_zeroed(), Some(true));
+    }
+
+    #[cfg(all(feature = "zeroize", feature = "std"))]
+    #[test]
+    fn test_watching_allocator_sees_unwiped_buffers() {
+        let key = watching_allocator::watch(|| BigUint::from_bytes_le(&[0xA5; 40]));
+        drop(key);
+        assert_eq!(watching_allocator::freed_zeroed(), Some(false));
+    }
+}
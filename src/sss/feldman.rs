@@ -0,0 +1,131 @@
+// Feldman verifiable secret sharing
+//
+// Shamir sharing over the exponents of a Group: the polynomial lives mod q and
+// the dealer publishes C_j = g^(a_j) mod p for every coefficient a_j. Since
+// g^f(i) = Π C_j^(i^j), each shareholder can check its share against the
+// commitments without learning anything beyond g^secret, which catches a
+// dealer handing out shares that don't lie on one polynomial.
+
+use super::{evaluate_shares, random_polynomial};
+use crate::ff::FiniteField;
+use crate::group::Group;
+use alloc::vec::Vec;
+use num_bigint::BigUint;
+use num_traits::One;
+use rand::RngCore;
+
+/// Splits `secret` modulo the group order `q` like `sss::split`, and returns
+/// the shares together with the commitments `g^(a_j) mod p` to the
+/// polynomial's coefficients, constant term first
+pub fn split<R: RngCore>(
+    secret: &BigUint,
+    threshold: usize,
+    shares: usize,
+    group: &Group,
+    rng: &mut R,
+) -> (Vec<(BigUint, BigUint)>, Vec<BigUint>) {
+    let coefficients = random_polynomial(secret, threshold, shares, &group.q, rng);
+    let commitments = coefficients
+        .iter()
+        .map(|a| FiniteField::exp_ct(&group.g, a, &group.p))
+        .collect();
+    (
+        evaluate_shares(&coefficients, shares, &group.q),
+        commitments,
+    )
+}
+
+/// Checks the share `(index, share)` against the dealer's commitments:
+/// `g^share == Π C_j^(index^j) mod p`
+pub fn verify_share(
+    index: &BigUint,
+    share: &BigUint,
+    commitments: &[BigUint],
+    group: &Group,
+) -> bool {
+    let mut expected = BigUint::one();
+    let mut index_power = BigUint::one();
+    for commitment in commitments {
+        let term = FiniteField::exp(commitment, &index_power, &group.p);
+        expected = FiniteField::mul(&expected, &term, &group.p);
+        index_power = FiniteField::mul(&index_power, index, &group.q);
+    }
+    FiniteField::exp(&group.g, share, &group.p) == expected % &group.p
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sss::reconstruct;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    // p = 2q + 1 with q = 1019, and g = 4 generating the subgroup of order q
+    fn create_test_group() -> Group {
+        Group::new(
+            BigUint::from(2039u32),
+            BigUint::from(1019u32),
+            BigUint::from(4u32),
+            BigUint::from(9u32),
+        )
+    }
+
+    #[test]
+    fn test_honest_shares_verify() {
+        let group = create_test_group();
+        let secret = BigUint::from(777u32);
+        let (shares, commitments) = split(&secret, 3, 5, &group, &mut StdRng::seed_from_u64(87));
+
+        assert_eq!(commitments.len(), 3);
+        assert_eq!(
+            commitments[0],
+            FiniteField::exp(&group.g, &secret, &group.p)
+        );
+        for (index, share) in &shares {
+            assert!(verify_share(index, share, &commitments, &group));
+        }
+        assert_eq!(reconstruct(&shares[1..4], &group.q), secret);
+    }
+
+    #[test]
+    fn test_tampered_share_fails() {
+        let group = create_test_group();
+        let (mut shares, commitments) = split(
+            &BigUint::from(5u32),
+            3,
+            5,
+            &group,
+            &mut StdRng::seed_from_u64(1),
+        );
+
+        shares[2].1 = FiniteField::add(&shares[2].1, &BigUint::from(1u32), &group.q);
+        for (i, (index, share)) in shares.iter().enumerate() {
+            assert_eq!(verify_share(index, share, &commitments, &group), i != 2);
+        }
+
+        // a share moved to another index fails too
+        assert!(!verify_share(
+            &shares[0].0,
+            &shares[1].1,
+            &commitments,
+            &group
+        ));
+    }
+
+    #[test]
+    fn test_tampered_commitment_fails() {
+        let group = create_test_group();
+        let (shares, mut commitments) = split(
+            &BigUint::from(5u32),
+            2,
+            3,
+            &group,
+            &mut StdRng::seed_from_u64(2),
+        );
+
+        commitments[1] = FiniteField::mul(&commitments[1], &group.g, &group.p);
+        for (index, share) in &shares {
+            assert!(!verify_share(index, share, &commitments, &group));
+        }
+    }
+}
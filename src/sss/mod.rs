@@ -0,0 +1,143 @@
+// Shamir secret sharing over a prime field
+//
+// The secret is the constant term of a random polynomial of degree
+// threshold - 1, and share i is the polynomial evaluated at x = i. Any
+// threshold shares determine the polynomial, and with it the secret, by
+// Lagrange interpolation at 0; fewer reveal nothing about it.
+
+use crate::ff::FiniteField;
+use alloc::vec::Vec;
+
+use num_bigint::{BigUint, RandBigInt};
+use num_traits::Zero;
+use rand::RngCore;
+
+pub mod feldman;
+
+/// Splits `secret` into `shares` points `(x, y)` with `x = 1..=shares`, any
+/// `threshold` of which reconstruct it.
+///
+/// `modulus` must be a prime larger than `shares`; the secret is reduced
+/// modulo it.
+pub fn split<R: RngCore>(
+    secret: &BigUint,
+    threshold: usize,
+    shares: usize,
+    modulus: &BigUint,
+    rng: &mut R,
+) -> Vec<(BigUint, BigUint)> {
+    let coefficients = random_polynomial(secret, threshold, shares, modulus, rng);
+    evaluate_shares(&coefficients, shares, modulus)
+}
+
+/// Recovers the secret from at least `threshold` distinct shares by Lagrange
+/// interpolation at 0. Too few shares give an unrelated value, not an error.
+pub fn reconstruct(shares: &[(BigUint, BigUint)], modulus: &BigUint) -> BigUint {
+    FiniteField::lagrange_interpolate(shares, &BigUint::zero(), modulus)
+}
+
+/// Draws the coefficients, constant term first, of a random polynomial of
+/// degree `threshold - 1` whose constant term is the secret
+fn random_polynomial<R: RngCore>(
+    secret: &BigUint,
+    threshold: usize,
+    shares: usize,
+    modulus: &BigUint,
+    rng: &mut R,
+) -> Vec<BigUint> {
+    assert!(
+        threshold >= 1 && threshold <= shares,
+        "threshold must be between 1 and the number of shares"
+    );
+    assert!(
+        &BigUint::from(shares) < modulus,
+        "modulus must be larger than the number of shares"
+    );
+
+    let mut coefficients = Vec::with_capacity(threshold);
+    coefficients.push(secret % modulus);
+    coefficients.extend((1..threshold).map(|_| rng.gen_biguint_below(modulus)));
+    coefficients
+}
+
+/// Evaluates the polynomial at `x = 1..=shares`
+fn evaluate_shares(
+    coefficients: &[BigUint],
+    shares: usize,
+    modulus: &BigUint,
+) -> Vec<(BigUint, BigUint)> {
+    (1..=shares)
+        .map(|i| {
+            let x = BigUint::from(i);
+            let y = FiniteField::poly_eval(coefficients, &x, modulus);
+            (x, y)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn modulus() -> BigUint {
+        BigUint::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+            16,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_reconstruct_with_exactly_threshold_shares() {
+        let modulus = modulus();
+        let secret = BigUint::from(0xC0FFEEu32);
+        let shares = split(&secret, 3, 5, &modulus, &mut StdRng::seed_from_u64(86));
+        assert_eq!(shares.len(), 5);
+
+        // every choice of three shares out of five
+        for i in 0..5 {
+            for j in i + 1..5 {
+                for k in j + 1..5 {
+                    let subset = [shares[i].clone(), shares[j].clone(), shares[k].clone()];
+                    assert_eq!(reconstruct(&subset, &modulus), secret);
+                }
+            }
+        }
+        assert_eq!(reconstruct(&shares, &modulus), secret);
+    }
+
+    #[test]
+    fn test_too_few_shares() {
+        let modulus = modulus();
+        let secret = BigUint::from(42u32);
+        let shares = split(&secret, 3, 5, &modulus, &mut StdRng::seed_from_u64(1));
+
+        assert_ne!(reconstruct(&shares[..2], &modulus), secret);
+    }
+
+    #[test]
+    fn test_threshold_one_shares_the_secret() {
+        let modulus = BigUint::from(101u32);
+        let secret = BigUint::from(150u32);
+        let shares = split(&secret, 1, 3, &modulus, &mut StdRng::seed_from_u64(2));
+
+        for (_, y) in &shares {
+            assert_eq!(y, &BigUint::from(49u32));
+        }
+        assert_eq!(reconstruct(&shares[1..2], &modulus), BigUint::from(49u32));
+    }
+
+    #[test]
+    #[should_panic(expected = "threshold must be between 1 and the number of shares")]
+    fn test_threshold_above_shares() {
+        split(
+            &BigUint::from(1u32),
+            4,
+            3,
+            &modulus(),
+            &mut StdRng::seed_from_u64(3),
+        );
+    }
+}
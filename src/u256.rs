@@ -0,0 +1,266 @@
+use core::cmp::Ordering;
+use num_bigint::BigUint;
+
+/// A 256-bit unsigned integer stored as four little-endian 64-bit limbs.
+///
+/// Unlike `BigUint` it never allocates, which is what makes `Secp256k1Fast`
+/// fast. Only the arithmetic the curve needs is provided.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct U256([u64; 4]);
+
+/// A modulus of the form `2^256 - c` with `c` well below `2^255`, which covers
+/// both the secp256k1 field prime and its group order. Products are reduced
+/// by folding the high half back in as `high·c` instead of dividing.
+pub(crate) struct Modulus {
+    m: U256,
+    c: U256,
+}
+
+impl U256 {
+    pub(crate) const ZERO: U256 = U256([0; 4]);
+    pub(crate) const ONE: U256 = U256([1, 0, 0, 0]);
+
+    /// Builds a value from limbs given least significant first
+    pub(crate) const fn from_limbs(limbs: [u64; 4]) -> Self {
+        U256(limbs)
+    }
+
+    /// Converts from a `BigUint`, or returns `None` if it doesn't fit in 256 bits
+    pub(crate) fn from_biguint(n: &BigUint) -> Option<Self> {
+        let digits = n.to_u64_digits();
+        if digits.len() > 4 {
+            return None;
+        }
+        let mut limbs = [0u64; 4];
+        limbs[..digits.len()].copy_from_slice(&digits);
+        Some(U256(limbs))
+    }
+
+    pub(crate) fn to_biguint(self) -> BigUint {
+        self.0.iter().rev().fold(BigUint::from(0u32), |acc, &limb| {
+            (acc << 64) | BigUint::from(limb)
+        })
+    }
+
+    pub(crate) fn is_zero(&self) -> bool {
+        self.0 == [0; 4]
+    }
+
+    pub(crate) fn bit(&self, i: usize) -> bool {
+        (self.0[i / 64] >> (i % 64)) & 1 == 1
+    }
+
+    /// Adds two values, returning the sum modulo `2^256` and the carry
+    fn overflowing_add(&self, other: &U256) -> (U256, bool) {
+        let mut limbs = [0u64; 4];
+        let mut carry = false;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let (sum, c1) = self.0[i].overflowing_add(other.0[i]);
+            let (sum, c2) = sum.overflowing_add(u64::from(carry));
+            *limb = sum;
+            carry = c1 || c2;
+        }
+        (U256(limbs), carry)
+    }
+
+    /// Subtracts two values, returning the difference modulo `2^256` and the borrow
+    fn overflowing_sub(&self, other: &U256) -> (U256, bool) {
+        let mut limbs = [0u64; 4];
+        let mut borrow = false;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let (diff, b1) = self.0[i].overflowing_sub(other.0[i]);
+            let (diff, b2) = diff.overflowing_sub(u64::from(borrow));
+            *limb = diff;
+            borrow = b1 || b2;
+        }
+        (U256(limbs), borrow)
+    }
+
+    /// Computes the full 512-bit product as (low, high) halves
+    fn widening_mul(&self, other: &U256) -> (U256, U256) {
+        let mut wide = [0u64; 8];
+        for i in 0..4 {
+            let mut carry = 0u128;
+            for j in 0..4 {
+                let t = u128::from(self.0[i]) * u128::from(other.0[j])
+                    + u128::from(wide[i + j])
+                    + carry;
+                wide[i + j] = t as u64;
+                carry = t >> 64;
+            }
+            wide[i + 4] = carry as u64;
+        }
+        (
+            U256([wide[0], wide[1], wide[2], wide[3]]),
+            U256([wide[4], wide[5], wide[6], wide[7]]),
+        )
+    }
+}
+
+impl Ord for U256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+impl PartialOrd for U256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Modulus {
+    /// The secp256k1 field prime `p = 2^256 - 2^32 - 977`
+    pub(crate) const SECP256K1_P: Modulus = Modulus {
+        m: U256::from_limbs([
+            0xFFFFFFFEFFFFFC2F,
+            0xFFFFFFFFFFFFFFFF,
+            0xFFFFFFFFFFFFFFFF,
+            0xFFFFFFFFFFFFFFFF,
+        ]),
+        c: U256::from_limbs([0x00000001000003D1, 0, 0, 0]),
+    };
+
+    /// The secp256k1 group order `n`
+    pub(crate) const SECP256K1_N: Modulus = Modulus {
+        m: U256::from_limbs([
+            0xBFD25E8CD0364141,
+            0xBAAEDCE6AF48A03B,
+            0xFFFFFFFFFFFFFFFE,
+            0xFFFFFFFFFFFFFFFF,
+        ]),
+        c: U256::from_limbs([0x402DA1732FC9BEBF, 0x4551231950B75FC4, 1, 0]),
+    };
+
+    pub(crate) fn value(&self) -> &U256 {
+        &self.m
+    }
+
+    /// Reduces any 256-bit value into `[0, m)`
+    pub(crate) fn reduce(&self, a: &U256) -> U256 {
+        // a < 2^256 < 2m, so one subtraction is enough
+        if *a >= self.m {
+            a.overflowing_sub(&self.m).0
+        } else {
+            *a
+        }
+    }
+
+    pub(crate) fn add(&self, a: &U256, b: &U256) -> U256 {
+        let (sum, carry) = a.overflowing_add(b);
+        if carry || sum >= self.m {
+            sum.overflowing_sub(&self.m).0
+        } else {
+            sum
+        }
+    }
+
+    pub(crate) fn sub(&self, a: &U256, b: &U256) -> U256 {
+        let (diff, borrow) = a.overflowing_sub(b);
+        if borrow {
+            diff.overflowing_add(&self.m).0
+        } else {
+            diff
+        }
+    }
+
+    pub(crate) fn mul(&self, a: &U256, b: &U256) -> U256 {
+        let (mut low, mut high) = a.widening_mul(b);
+        // high·2^256 ≡ high·c (mod m); each fold shrinks the high half
+        while !high.is_zero() {
+            let (folded_low, folded_high) = high.widening_mul(&self.c);
+            let (sum, carry) = low.overflowing_add(&folded_low);
+            low = sum;
+            high = folded_high
+                .overflowing_add(&U256::from_limbs([u64::from(carry), 0, 0, 0]))
+                .0;
+        }
+        self.reduce(&low)
+    }
+
+    pub(crate) fn square(&self, a: &U256) -> U256 {
+        self.mul(a, a)
+    }
+
+    pub(crate) fn pow(&self, base: &U256, exponent: &U256) -> U256 {
+        let mut result = U256::ONE;
+        for i in (0..256).rev() {
+            result = self.square(&result);
+            if exponent.bit(i) {
+                result = self.mul(&result, base);
+            }
+        }
+        result
+    }
+
+    /// Inverts `a` with Fermat's little theorem, so `m` must be prime.
+    /// Zero maps to zero.
+    pub(crate) fn inv(&self, a: &U256) -> U256 {
+        let exponent = self.m.overflowing_sub(&U256::from_limbs([2, 0, 0, 0])).0;
+        self.pow(a, &exponent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curves::secp256k1::create_secp256k1_weierstrass;
+    use crate::ec::EllipticCurve;
+    use crate::ff::FiniteField;
+    use num_bigint::RandBigInt;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_moduli_match_secp256k1() {
+        let curve = create_secp256k1_weierstrass();
+        assert_eq!(
+            &Modulus::SECP256K1_P.value().to_biguint(),
+            curve.field_modulus()
+        );
+        assert_eq!(&Modulus::SECP256K1_N.value().to_biguint(), curve.order());
+
+        let two_256 = BigUint::from(1u32) << 256;
+        for modulus in [&Modulus::SECP256K1_P, &Modulus::SECP256K1_N] {
+            assert_eq!(modulus.m.to_biguint() + modulus.c.to_biguint(), two_256);
+        }
+    }
+
+    #[test]
+    fn test_arithmetic_matches_biguint() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for modulus in [&Modulus::SECP256K1_P, &Modulus::SECP256K1_N] {
+            let m = modulus.value().to_biguint();
+            let mut values = vec![BigUint::from(0u32), BigUint::from(1u32), &m - 1u32];
+            values.extend((0..20).map(|_| rng.gen_biguint_below(&m)));
+
+            for a in &values {
+                let ua = U256::from_biguint(a).unwrap();
+                assert_eq!(ua.to_biguint(), *a);
+                assert_eq!(modulus.inv(&ua).to_biguint(), a.modpow(&(&m - 2u32), &m));
+                for b in &values {
+                    let ub = U256::from_biguint(b).unwrap();
+                    assert_eq!(
+                        modulus.add(&ua, &ub).to_biguint(),
+                        FiniteField::add(a, b, &m)
+                    );
+                    assert_eq!(
+                        modulus.sub(&ua, &ub).to_biguint(),
+                        FiniteField::sub(a, b, &m)
+                    );
+                    assert_eq!(
+                        modulus.mul(&ua, &ub).to_biguint(),
+                        FiniteField::mul(a, b, &m)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_biguint_rejects_wide_values() {
+        assert_eq!(U256::from_biguint(&(BigUint::from(1u32) << 256)), None);
+        let max = (BigUint::from(1u32) << 256) - 1u32;
+        assert_eq!(U256::from_biguint(&max).unwrap().to_biguint(), max);
+    }
+}
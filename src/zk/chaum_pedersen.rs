@@ -1,29 +1,122 @@
 // Chaum-Pedersen Protocol
 
+use crate::ec::EllipticCurve;
 use crate::ff::FiniteField;
-use crate::group::Group;
+use crate::group::{DlogGroup, Group};
+use crate::point::Point;
+use crate::zk::transcript::Transcript;
 use num_bigint::BigUint;
 use log::debug;
-
-pub struct ChaumPedersen {
-    pub group: Group,
+#[cfg(feature = "serde")]
+use crate::encoding::biguint_hex;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+pub struct ChaumPedersen<G: DlogGroup = Group> {
+    pub group: G,
+    pub g: G::Element,
+    pub h: G::Element,
 }
 
-pub struct Commitment {
-    pub r1: BigUint, // r1 = g^k mod p
-    pub r2: BigUint, // r2 = h^k mod p
-    pub y1: BigUint, // y1 = g^x mod p
-    pub y2: BigUint, // y2 = h^x mod p
+pub struct Commitment<E = BigUint> {
+    pub r1: E, // r1 = g^k
+    pub r2: E, // r2 = h^k
+    pub y1: E, // y1 = g^x
+    pub y2: E, // y2 = h^x
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Challenge {
+    #[cfg_attr(feature = "serde", serde(with = "biguint_hex"))]
     pub c: BigUint, // random challenge
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Proof {
+    #[cfg_attr(feature = "serde", serde(with = "biguint_hex"))]
     pub s: BigUint, // s = k - cx mod q
 }
 
+// A complete run of the protocol over a Group, e.g. to store a proof or send
+// it to a verifier in one piece
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ProofTranscript {
+    pub commitment: Commitment,
+    pub challenge: Challenge,
+    pub proof: Proof,
+}
+
+// Commitment is generic over the group element, so serde goes through this
+// mirror of Commitment<BigUint> to encode the fields as hex
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct CommitmentHex {
+    #[serde(with = "biguint_hex")]
+    r1: BigUint,
+    #[serde(with = "biguint_hex")]
+    r2: BigUint,
+    #[serde(with = "biguint_hex")]
+    y1: BigUint,
+    #[serde(with = "biguint_hex")]
+    y2: BigUint,
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Commitment {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        CommitmentHex {
+            r1: self.r1.clone(),
+            r2: self.r2.clone(),
+            y1: self.y1.clone(),
+            y2: self.y2.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Commitment {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let CommitmentHex { r1, r2, y1, y2 } = CommitmentHex::deserialize(deserializer)?;
+        Ok(Commitment { r1, r2, y1, y2 })
+    }
+}
+
+// The prover's secrets for an OR proof: the index of the branch whose
+// statement holds, its witness x and nonce k, and the challenge and response
+// chosen in advance to simulate the other branch
+pub struct OrSecret {
+    pub real: usize,
+    pub x: BigUint,
+    pub k: BigUint,
+    pub simulated_c: BigUint,
+    pub simulated_s: BigUint,
+}
+
+// (r1, r2) for each branch, sent before the challenge
+pub struct OrCommitment<E = BigUint> {
+    pub r: [(E, E); 2],
+}
+
+pub struct OrProof {
+    pub c: [BigUint; 2], // per-branch challenges, summing to c mod q
+    pub s: [BigUint; 2], // per-branch responses
+}
+
+// Intermediate values of a proof run, for comparing against other
+// implementations
+pub struct Trace<E = BigUint> {
+    pub g_k: E,        // g^k
+    pub h_k: E,        // h^k
+    pub g_x: E,        // g^x
+    pub h_x: E,        // h^x
+    pub cx: BigUint,   // c * x mod q
+    pub g_s: E,        // g^s
+    pub h_s: E,        // h^s
+    pub y1_c: E,       // y1^c
+    pub y2_c: E,       // y2^c
+}
+
 impl ChaumPedersen {
     // The Chaum-Pedersen Protocol is a zero-knowledge proof system that allows
     // a prover to demonstrate knowledge of a discrete logarithm without
@@ -61,18 +154,69 @@ impl ChaumPedersen {
     // value, and that the same x is used in both y1 and y2.
 
     pub fn new(group: Group) -> Self {
-        Self { group }
+        let (g, h) = (group.g.clone(), group.h.clone());
+        Self { group, g, h }
     }
 
-    pub fn commit(&self, x: &BigUint, k: &BigUint) -> Commitment {
+    // makes the protocol non-interactive (Fiat-Shamir): the challenge is
+    // derived from a transcript of the group, both generators and the
+    // commitment instead of being sent by the verifier
+    pub fn fiat_shamir_challenge(&self, commitment: &Commitment) -> Challenge {
+        let mut transcript = Transcript::new(b"ecc-rust chaum-pedersen");
+        self.challenge_from_transcript(&mut transcript, commitment)
+    }
+
+    // the Fiat-Shamir step on a caller's transcript, so the challenge also
+    // binds whatever was appended before (a session id, earlier messages):
+    // absorbs p, q, g, h and the commitment, then draws c mod q
+    pub fn challenge_from_transcript(&self, transcript: &mut Transcript, commitment: &Commitment) -> Challenge {
+        transcript.append_biguint(b"p", &self.group.p);
+        transcript.append_biguint(b"q", &self.group.q);
+        transcript.append_biguint(b"g", &self.g);
+        transcript.append_biguint(b"h", &self.h);
+        transcript.append_biguint(b"y1", &commitment.y1);
+        transcript.append_biguint(b"y2", &commitment.y2);
+        transcript.append_biguint(b"r1", &commitment.r1);
+        transcript.append_biguint(b"r2", &commitment.r2);
+        self.challenge(&transcript.challenge_biguint(b"c", &self.group.q))
+    }
+}
+
+impl ProofTranscript {
+    // checks the stored proof against the stored commitment and challenge
+    pub fn verify_self(&self, cp: &ChaumPedersen) -> bool {
+        cp.verify(&self.commitment, &self.challenge, &self.proof)
+    }
+}
+
+impl<C: EllipticCurve> ChaumPedersen<C> {
+    // proves equality of discrete logs with respect to the curve's base point G
+    // and a second point H, i.e. that Y1 = x·G and Y2 = x·H share x; nobody
+    // should know the discrete log of H to base G, so derive it with e.g.
+    // WeierstrassCurve::hash_to_curve
+    pub fn for_curve(curve: C, h: Point) -> Self {
+        let g = curve.base_point().clone();
+        Self::with_generators(curve, g, h)
+    }
+}
+
+// The protocol only needs a prime-order group and two generators, so it runs
+// unchanged over any DlogGroup. For elliptic curves "g^k" reads as k·G and the
+// group operation is point addition.
+impl<G: DlogGroup> ChaumPedersen<G> {
+    pub fn with_generators(group: G, g: G::Element, h: G::Element) -> Self {
+        Self { group, g, h }
+    }
+
+    pub fn commit(&self, x: &BigUint, k: &BigUint) -> Commitment<G::Element> {
         debug!("Generating commitment");
         let commitment = Commitment {
-            r1: FiniteField::exp(&self.group.g, k, &self.group.p),
-            r2: FiniteField::exp(&self.group.h, k, &self.group.p),
-            y1: FiniteField::exp(&self.group.g, x, &self.group.p),
-            y2: FiniteField::exp(&self.group.h, x, &self.group.p),
+            r1: self.group.scalar_mul_secret(&self.g, k),
+            r2: self.group.scalar_mul_secret(&self.h, k),
+            y1: self.group.scalar_mul_secret(&self.g, x),
+            y2: self.group.scalar_mul_secret(&self.h, x),
         };
-        debug!("Commitment generated: r1={}, r2={}, y1={}, y2={}", commitment.r1, commitment.r2, commitment.y1, commitment.y2);
+        debug!("Commitment generated: r1={:?}, r2={:?}, y1={:?}, y2={:?}", commitment.r1, commitment.r2, commitment.y1, commitment.y2);
         commitment
     }
 
@@ -84,30 +228,56 @@ impl ChaumPedersen {
     // generates s = k - cx mod q
     pub fn proof(&self, k: &BigUint, c: &BigUint, x: &BigUint) -> Proof {
         debug!("Generating proof");
-        let cx = FiniteField::mul(c, x, &self.group.q);
-        let s = FiniteField::sub(k, &cx, &self.group.q);
+        let cx = FiniteField::mul(c, x, self.group.order());
+        let s = FiniteField::sub(k, &cx, self.group.order());
         debug!("Proof generated: s={}", s);
         Proof { s }
     }
 
-    // r1 == g^s * y1^c mod p
-    // r2 == h^s * y2^c mod p
+    // runs commit and proof for a fixed challenge c and records every
+    // intermediate exponentiation along the way
+    pub fn prove_with_trace(
+        &self,
+        x: &BigUint,
+        k: &BigUint,
+        c: &BigUint,
+    ) -> (Commitment<G::Element>, Proof, Trace<G::Element>) {
+        let commitment = self.commit(x, k);
+        let proof = self.proof(k, c, x);
+
+        let trace = Trace {
+            g_k: commitment.r1.clone(),
+            h_k: commitment.r2.clone(),
+            g_x: commitment.y1.clone(),
+            h_x: commitment.y2.clone(),
+            cx: FiniteField::mul(c, x, self.group.order()),
+            g_s: self.group.scalar_mul(&self.g, &proof.s),
+            h_s: self.group.scalar_mul(&self.h, &proof.s),
+            y1_c: self.group.scalar_mul(&commitment.y1, c),
+            y2_c: self.group.scalar_mul(&commitment.y2, c),
+        };
+
+        (commitment, proof, trace)
+    }
+
+    // r1 == g^s * y1^c
+    // r2 == h^s * y2^c
     // returns true if valid, false otherwise
-    pub fn verify(&self, commitment: &Commitment, challenge: &Challenge, proof: &Proof) -> bool {
+    pub fn verify(&self, commitment: &Commitment<G::Element>, challenge: &Challenge, proof: &Proof) -> bool {
         debug!("Verifying Chaum-Pedersen proof");
-        debug!("Commitment: r1={}, r2={}, y1={}, y2={}", commitment.r1, commitment.r2, commitment.y1, commitment.y2);
+        debug!("Commitment: r1={:?}, r2={:?}, y1={:?}, y2={:?}", commitment.r1, commitment.r2, commitment.y1, commitment.y2);
         debug!("Challenge: c={}", challenge.c);
         debug!("Proof: s={}", proof.s);
 
-        let g_s = FiniteField::exp(&self.group.g, &proof.s, &self.group.p);
-        let y1_c = FiniteField::exp(&commitment.y1, &challenge.c, &self.group.p);
-        let left_side = FiniteField::mul(&g_s, &y1_c, &self.group.p);
-        debug!("Left side verification: g^s * y1^c mod p = {}", left_side);
+        let g_s = self.group.scalar_mul(&self.g, &proof.s);
+        let y1_c = self.group.scalar_mul(&commitment.y1, &challenge.c);
+        let left_side = self.group.combine(&g_s, &y1_c);
+        debug!("Left side verification: g^s * y1^c = {:?}", left_side);
 
-        let h_s = FiniteField::exp(&self.group.h, &proof.s, &self.group.p);
-        let y2_c = FiniteField::exp(&commitment.y2, &challenge.c, &self.group.p);
-        let right_side = FiniteField::mul(&h_s, &y2_c, &self.group.p);
-        debug!("Right side verification: h^s * y2^c mod p = {}", right_side);
+        let h_s = self.group.scalar_mul(&self.h, &proof.s);
+        let y2_c = self.group.scalar_mul(&commitment.y2, &challenge.c);
+        let right_side = self.group.combine(&h_s, &y2_c);
+        debug!("Right side verification: h^s * y2^c = {:?}", right_side);
 
         let result = left_side == commitment.r1 && right_side == commitment.r2;
         debug!("Verification result: {}", result);
@@ -116,12 +286,89 @@ impl ChaumPedersen {
     }
 }
 
+// Disjunctive (Cramer-Damgard-Schoenmakers) composition: proves that one of
+// two statements (y1, y2) has log_g y1 = log_h y2, without revealing which.
+//
+// 1. Commitment: for the true branch the prover commits as usual with
+//    (g^k, h^k). For the other branch it picks the challenge c' and response
+//    s' up front and solves the verification equations for the commitment,
+//    (g^s' * y1^c', h^s' * y2^c').
+// 2. Challenge: the verifier sends c.
+// 3. Proof: the true branch gets the challenge c - c' mod q and the usual
+//    response k - (c - c')x mod q.
+// 4. Verification: the two challenges must sum to c, and both branches must
+//    pass the Chaum-Pedersen check. Only the simulated branch's challenge was
+//    fixed before c, so a prover without a witness for either branch fails.
+//
+// Ballots in voting schemes use this to prove an encrypted value is 0 or 1.
+impl<G: DlogGroup> ChaumPedersen<G> {
+    pub fn commit_or(&self, statements: &[(G::Element, G::Element); 2], secret: &OrSecret) -> OrCommitment<G::Element> {
+        debug!("Generating OR commitment");
+        let commit_branch = |i: usize| {
+            let (y1, y2) = &statements[i];
+            if i == secret.real {
+                (
+                    self.group.scalar_mul_secret(&self.g, &secret.k),
+                    self.group.scalar_mul_secret(&self.h, &secret.k),
+                )
+            } else {
+                self.branch_commitment(y1, y2, &secret.simulated_c, &secret.simulated_s)
+            }
+        };
+        OrCommitment { r: [commit_branch(0), commit_branch(1)] }
+    }
+
+    pub fn prove_or(&self, secret: &OrSecret, c: &BigUint) -> OrProof {
+        debug!("Generating OR proof");
+        let q = self.group.order();
+        let real_c = FiniteField::sub(c, &secret.simulated_c, q);
+        let real_s = self.proof(&secret.k, &real_c, &secret.x).s;
+
+        let (simulated_c, simulated_s) = (secret.simulated_c.clone() % q, secret.simulated_s.clone() % q);
+        if secret.real == 0 {
+            OrProof { c: [real_c, simulated_c], s: [real_s, simulated_s] }
+        } else {
+            OrProof { c: [simulated_c, real_c], s: [simulated_s, real_s] }
+        }
+    }
+
+    // c0 + c1 == c mod q, and for each branch i
+    // r1_i == g^s_i * y1_i^c_i
+    // r2_i == h^s_i * y2_i^c_i
+    pub fn verify_or(
+        &self,
+        statements: &[(G::Element, G::Element); 2],
+        commitment: &OrCommitment<G::Element>,
+        challenge: &Challenge,
+        proof: &OrProof,
+    ) -> bool {
+        debug!("Verifying Chaum-Pedersen OR proof");
+        let q = self.group.order();
+        if FiniteField::add(&proof.c[0], &proof.c[1], q) != &challenge.c % q {
+            debug!("Branch challenges do not sum to c");
+            return false;
+        }
+
+        (0..2).all(|i| {
+            let (y1, y2) = &statements[i];
+            self.branch_commitment(y1, y2, &proof.c[i], &proof.s[i]) == commitment.r[i]
+        })
+    }
+
+    // (g^s * y1^c, h^s * y2^c), the commitment a valid branch must match
+    fn branch_commitment(&self, y1: &G::Element, y2: &G::Element, c: &BigUint, s: &BigUint) -> (G::Element, G::Element) {
+        (
+            self.group.combine(&self.group.scalar_mul(&self.g, s), &self.group.scalar_mul(y1, c)),
+            self.group.combine(&self.group.scalar_mul(&self.h, s), &self.group.scalar_mul(y2, c)),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::group::Group;
     use num_bigint::BigUint;
-    use env_logger;
 
     fn init() {
       let _ = env_logger::builder().is_test(true).try_init();
@@ -194,6 +441,338 @@ mod tests {
             let proof = chaum_pedersen.proof(&k, &challenge.c, &x);
             assert!(chaum_pedersen.verify(&commitment, &challenge, &proof));
         }
+
+        #[test]
+        fn test_prove_with_trace() {
+            let (chaum_pedersen, x, k) = setup();
+            let c = BigUint::from(5u32);
+            let (p, q) = (&chaum_pedersen.group.p, &chaum_pedersen.group.q);
+
+            let (commitment, proof, trace) = chaum_pedersen.prove_with_trace(&x, &k, &c);
+
+            assert_eq!(trace.g_k, commitment.r1);
+            assert_eq!(trace.h_k, commitment.r2);
+            assert_eq!(trace.g_x, commitment.y1);
+            assert_eq!(trace.h_x, commitment.y2);
+            assert_eq!(FiniteField::sub(&k, &trace.cx, q), proof.s);
+            assert_eq!(FiniteField::mul(&trace.g_s, &trace.y1_c, p), commitment.r1);
+            assert_eq!(FiniteField::mul(&trace.h_s, &trace.y2_c, p), commitment.r2);
+
+            let challenge = chaum_pedersen.challenge(&c);
+            assert!(chaum_pedersen.verify(&commitment, &challenge, &proof));
+        }
+
+        #[test]
+        fn test_fiat_shamir_challenge() {
+            let (chaum_pedersen, x, k) = setup();
+
+            let commitment = chaum_pedersen.commit(&x, &k);
+            let challenge = chaum_pedersen.fiat_shamir_challenge(&commitment);
+            let proof = chaum_pedersen.proof(&k, &challenge.c, &x);
+            assert!(chaum_pedersen.verify(&commitment, &challenge, &proof));
+            assert_eq!(chaum_pedersen.fiat_shamir_challenge(&commitment).c, challenge.c);
+
+            // with q = 11 two commitments can share a challenge, but not all of them
+            let challenges: Vec<BigUint> = (1u32..6)
+                .map(|k| chaum_pedersen.fiat_shamir_challenge(&chaum_pedersen.commit(&x, &BigUint::from(k))).c)
+                .collect();
+            assert!(challenges.iter().any(|c| c != &challenges[0]));
+        }
+
+        #[test]
+        fn test_challenge_from_transcript_is_deterministic() {
+            let (chaum_pedersen, x, k) = setup();
+            let commitment = chaum_pedersen.commit(&x, &k);
+            let session = |id: &[u8]| {
+                let mut transcript = Transcript::new(b"ecc-rust chaum-pedersen");
+                transcript.append_bytes(b"session", id);
+                transcript
+            };
+
+            let challenge = chaum_pedersen.challenge_from_transcript(&mut session(b"1"), &commitment);
+            for _ in 0..3 {
+                assert_eq!(chaum_pedersen.challenge_from_transcript(&mut session(b"1"), &commitment).c, challenge.c);
+            }
+            assert!(challenge.c < chaum_pedersen.group.q);
+
+            // with nothing appended first it is the plain Fiat-Shamir challenge
+            let mut fresh = Transcript::new(b"ecc-rust chaum-pedersen");
+            assert_eq!(
+                chaum_pedersen.challenge_from_transcript(&mut fresh, &commitment).c,
+                chaum_pedersen.fiat_shamir_challenge(&commitment).c
+            );
+            // the session id is bound into the challenge
+            let sessions: Vec<BigUint> = [&b"2"[..], b"3", b"4", b"5"]
+                .iter()
+                .map(|id| chaum_pedersen.challenge_from_transcript(&mut session(id), &commitment).c)
+                .collect();
+            assert!(sessions.iter().any(|c| c != &challenge.c));
+
+            let proof = chaum_pedersen.proof(&k, &challenge.c, &x);
+            assert!(chaum_pedersen.verify(&commitment, &challenge, &proof));
+        }
+
+        #[test]
+        fn test_transcript_verify_self() {
+            let (chaum_pedersen, x, k) = setup();
+
+            let commitment = chaum_pedersen.commit(&x, &k);
+            let challenge = chaum_pedersen.fiat_shamir_challenge(&commitment);
+            let proof = chaum_pedersen.proof(&k, &challenge.c, &x);
+            let mut transcript = ProofTranscript { commitment, challenge, proof };
+            assert!(transcript.verify_self(&chaum_pedersen));
+
+            transcript.proof.s += 1u32;
+            assert!(!transcript.verify_self(&chaum_pedersen));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    mod serialization {
+        use super::*;
+
+        fn transcript() -> (ChaumPedersen, ProofTranscript) {
+            let group = Group::new(
+                BigUint::from(23u32),
+                BigUint::from(11u32),
+                BigUint::from(4u32),
+                BigUint::from(9u32),
+            );
+            let chaum_pedersen = ChaumPedersen::new(group);
+            let (x, k) = (BigUint::from(2u32), BigUint::from(3u32));
+
+            let commitment = chaum_pedersen.commit(&x, &k);
+            let challenge = chaum_pedersen.fiat_shamir_challenge(&commitment);
+            let proof = chaum_pedersen.proof(&k, &challenge.c, &x);
+            (chaum_pedersen, ProofTranscript { commitment, challenge, proof })
+        }
+
+        #[test]
+        fn test_json_round_trip() {
+            let (_, transcript) = transcript();
+            let json = serde_json::to_string(&transcript).unwrap();
+            let decoded: ProofTranscript = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(decoded.commitment.r1, transcript.commitment.r1);
+            assert_eq!(decoded.commitment.r2, transcript.commitment.r2);
+            assert_eq!(decoded.commitment.y1, transcript.commitment.y1);
+            assert_eq!(decoded.commitment.y2, transcript.commitment.y2);
+            assert_eq!(decoded.challenge.c, transcript.challenge.c);
+            assert_eq!(decoded.proof.s, transcript.proof.s);
+
+            // g^3 = 64 mod 23 = 18 = 0x12
+            assert!(json.contains("\"r1\":\"12\""));
+        }
+
+        #[test]
+        fn test_verify_after_deserialize() {
+            let (chaum_pedersen, transcript) = transcript();
+            let json = serde_json::to_string(&transcript).unwrap();
+
+            let decoded: ProofTranscript = serde_json::from_str(&json).unwrap();
+            assert!(decoded.verify_self(&chaum_pedersen));
+
+            let challenge: Challenge = serde_json::from_str(&serde_json::to_string(&decoded.challenge).unwrap()).unwrap();
+            let proof: Proof = serde_json::from_str(r#"{"s":"A"}"#).unwrap();
+            assert_eq!(proof.s, BigUint::from(10u32));
+            assert_eq!(challenge.c, transcript.challenge.c);
+
+            let invalid = json.replace("\"r1\":\"12\"", "\"r1\":\"xyz\"");
+            assert!(serde_json::from_str::<ProofTranscript>(&invalid).is_err());
+        }
+    }
+
+    mod curve_example {
+        use super::*;
+        use crate::ec::{EllipticCurve, WeierstrassCurve};
+        use crate::point::Point;
+
+        // y^2 = x^3 + 2x + 2 over F_17, whose 19 points form a group of prime order
+        fn setup() -> ChaumPedersen<WeierstrassCurve> {
+            init();
+            let curve = WeierstrassCurve::new(
+                BigUint::from(2u32),
+                BigUint::from(2u32),
+                BigUint::from(17u32),
+                BigUint::from(19u32),
+                Point::Coordinates(BigUint::from(5u32), BigUint::from(1u32)),
+            );
+            let g = curve.base_point().clone();
+            let h = curve.mul(&g, &BigUint::from(3u32));
+            ChaumPedersen::with_generators(curve, g, h)
+        }
+
+        #[test]
+        fn test_protocol_over_curve_group() {
+            let chaum_pedersen = setup();
+            let (x, k) = (BigUint::from(7u32), BigUint::from(11u32));
+
+            let commitment = chaum_pedersen.commit(&x, &k);
+            let challenge = chaum_pedersen.challenge(&BigUint::from(5u32));
+            let proof = chaum_pedersen.proof(&k, &challenge.c, &x);
+            assert!(chaum_pedersen.verify(&commitment, &challenge, &proof));
+
+            let wrong_proof = chaum_pedersen.proof(&k, &challenge.c, &BigUint::from(8u32));
+            assert!(!chaum_pedersen.verify(&commitment, &challenge, &wrong_proof));
+        }
+    }
+
+    mod or_proof {
+        use super::*;
+        use crate::ec::{EllipticCurve, WeierstrassCurve};
+        use crate::point::Point;
+
+        // ElGamal-style ballot (a, b) = (g^r, h^r * g^m), with the statements
+        // "m = 0": log_g a = log_h b and "m = 1": log_g a = log_h (b / g)
+        fn ballot_statements<G: DlogGroup>(
+            chaum_pedersen: &ChaumPedersen<G>,
+            m: u32,
+            r: &BigUint,
+        ) -> [(G::Element, G::Element); 2] {
+            let group = &chaum_pedersen.group;
+            let a = group.scalar_mul(&chaum_pedersen.g, r);
+            let b = group.combine(
+                &group.scalar_mul(&chaum_pedersen.h, r),
+                &group.scalar_mul(&chaum_pedersen.g, &BigUint::from(m)),
+            );
+            let g_inv = group.scalar_mul(&chaum_pedersen.g, &(group.order() - 1u32));
+            let b_over_g = group.combine(&b, &g_inv);
+            [(a.clone(), b), (a, b_over_g)]
+        }
+
+        fn prove_and_verify<G: DlogGroup>(chaum_pedersen: &ChaumPedersen<G>, m: u32, real: usize) -> bool {
+            let r = BigUint::from(7u32);
+            let statements = ballot_statements(chaum_pedersen, m, &r);
+            let secret = OrSecret {
+                real,
+                x: r,
+                k: BigUint::from(5u32),
+                simulated_c: BigUint::from(3u32),
+                simulated_s: BigUint::from(9u32),
+            };
+
+            let commitment = chaum_pedersen.commit_or(&statements, &secret);
+            let challenge = chaum_pedersen.challenge(&BigUint::from(6u32));
+            let proof = chaum_pedersen.prove_or(&secret, &challenge.c);
+            chaum_pedersen.verify_or(&statements, &commitment, &challenge, &proof)
+        }
+
+        fn toy_setup() -> ChaumPedersen {
+            init();
+            ChaumPedersen::new(Group::new(
+                BigUint::from(23u32),
+                BigUint::from(11u32),
+                BigUint::from(4u32),
+                BigUint::from(9u32),
+            ))
+        }
+
+        #[test]
+        fn test_zero_or_one() {
+            let chaum_pedersen = toy_setup();
+            assert!(prove_and_verify(&chaum_pedersen, 0, 0));
+            assert!(prove_and_verify(&chaum_pedersen, 1, 1));
+        }
+
+        #[test]
+        fn test_false_statements_fail() {
+            let chaum_pedersen = toy_setup();
+            // claiming the wrong branch, or a ballot for 2 with either branch
+            assert!(!prove_and_verify(&chaum_pedersen, 0, 1));
+            assert!(!prove_and_verify(&chaum_pedersen, 1, 0));
+            assert!(!prove_and_verify(&chaum_pedersen, 2, 0));
+            assert!(!prove_and_verify(&chaum_pedersen, 2, 1));
+        }
+
+        #[test]
+        fn test_challenges_must_sum_to_c() {
+            let chaum_pedersen = toy_setup();
+            let r = BigUint::from(7u32);
+            let statements = ballot_statements(&chaum_pedersen, 1, &r);
+            let secret = OrSecret {
+                real: 1,
+                x: r,
+                k: BigUint::from(5u32),
+                simulated_c: BigUint::from(3u32),
+                simulated_s: BigUint::from(9u32),
+            };
+
+            let commitment = chaum_pedersen.commit_or(&statements, &secret);
+            let proof = chaum_pedersen.prove_or(&secret, &BigUint::from(6u32));
+            let other_challenge = chaum_pedersen.challenge(&BigUint::from(8u32));
+            assert!(!chaum_pedersen.verify_or(&statements, &commitment, &other_challenge, &proof));
+        }
+
+        #[test]
+        fn test_zero_or_one_over_curve() {
+            init();
+            // y^2 = x^3 + 2x + 2 over F_17, a group of prime order 19
+            let curve = WeierstrassCurve::new(
+                BigUint::from(2u32),
+                BigUint::from(2u32),
+                BigUint::from(17u32),
+                BigUint::from(19u32),
+                Point::Coordinates(BigUint::from(5u32), BigUint::from(1u32)),
+            );
+            let g = curve.base_point().clone();
+            let h = curve.mul(&g, &BigUint::from(3u32));
+            let chaum_pedersen = ChaumPedersen::with_generators(curve, g, h);
+
+            assert!(prove_and_verify(&chaum_pedersen, 0, 0));
+            assert!(prove_and_verify(&chaum_pedersen, 1, 1));
+            assert!(!prove_and_verify(&chaum_pedersen, 2, 1));
+        }
+    }
+
+    mod secp256k1_example {
+        use super::*;
+        use crate::curves::secp256k1::create_secp256k1_weierstrass;
+        use crate::ec::WeierstrassCurve;
+
+        fn setup() -> ChaumPedersen<WeierstrassCurve> {
+            init();
+            let curve = create_secp256k1_weierstrass();
+            let h = curve.hash_to_curve(b"ecc-rust chaum-pedersen H");
+            ChaumPedersen::for_curve(curve, h)
+        }
+
+        #[test]
+        fn test_same_discrete_log() {
+            let chaum_pedersen = setup();
+            let x = BigUint::parse_bytes(
+                b"1d8ef5bd3b7cbfcc0a53b6aef4e6d3c2a5a1c6a8a40ae7b5e47d3f5d3c2b1a09",
+                16,
+            )
+            .unwrap();
+            let k = BigUint::parse_bytes(
+                b"5e2f1c0d9b8a79685746352413021f0e1d2c3b4a5968778695a4b3c2d1e0f1a2",
+                16,
+            )
+            .unwrap();
+
+            let commitment = chaum_pedersen.commit(&x, &k);
+            assert_eq!(commitment.y1, chaum_pedersen.group.mul(&chaum_pedersen.g, &x));
+            assert_eq!(commitment.y2, chaum_pedersen.group.mul(&chaum_pedersen.h, &x));
+
+            let challenge = chaum_pedersen.challenge(&BigUint::from(0xC0FFEEu32));
+            let proof = chaum_pedersen.proof(&k, &challenge.c, &x);
+            assert!(chaum_pedersen.verify(&commitment, &challenge, &proof));
+        }
+
+        #[test]
+        fn test_different_discrete_logs() {
+            let chaum_pedersen = setup();
+            let x = BigUint::from(123456789u32);
+            let k = BigUint::from(987654321u32);
+
+            // Y2 = x'·H for a different x', so no s satisfies both equations
+            let mut commitment = chaum_pedersen.commit(&x, &k);
+            commitment.y2 = chaum_pedersen.group.mul(&chaum_pedersen.h, &(&x + 1u32));
+
+            let challenge = chaum_pedersen.challenge(&BigUint::from(0xC0FFEEu32));
+            let proof = chaum_pedersen.proof(&k, &challenge.c, &x);
+            assert!(!chaum_pedersen.verify(&commitment, &challenge, &proof));
+        }
     }
 
     mod real_example {
@@ -0,0 +1,154 @@
+// Schnorr proof of knowledge of a discrete logarithm
+
+use crate::ff::FiniteField;
+use crate::group::Group;
+use crate::zk::transcript::Transcript;
+use log::debug;
+use num_bigint::BigUint;
+
+/// A non-interactive proof of knowledge of `x` with `y = g^x mod p`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DlogProof {
+    pub t: BigUint, // t = g^k
+    pub s: BigUint, // s = k - cx mod q
+}
+
+/// Proves knowledge of `x` for `y = g^x mod p`, using the nonce `k`.
+///
+/// This is the Schnorr protocol made non-interactive with Fiat-Shamir: the
+/// challenge is drawn from a `Transcript` over the group, `y` and `t`. `k` must be uniformly random
+/// in `[1, q)` and never reused, or `x` leaks.
+pub fn prove(group: &Group, x: &BigUint, k: &BigUint) -> DlogProof {
+    debug!("Generating discrete log proof");
+    let y = FiniteField::exp_ct(&group.g, x, &group.p);
+    let t = FiniteField::exp_ct(&group.g, k, &group.p);
+    let c = challenge(group, &y, &t);
+
+    let cx = FiniteField::mul(&c, x, &group.q);
+    let s = FiniteField::sub(k, &cx, &group.q);
+    DlogProof { t, s }
+}
+
+/// Checks `g^s · y^c == t` for the challenge recomputed from `y` and `t`
+pub fn verify(group: &Group, y: &BigUint, proof: &DlogProof) -> bool {
+    debug!("Verifying discrete log proof");
+    let c = challenge(group, y, &proof.t);
+    let g_s = FiniteField::exp(&group.g, &proof.s, &group.p);
+    let y_c = FiniteField::exp(y, &c, &group.p);
+    FiniteField::mul(&g_s, &y_c, &group.p) == proof.t
+}
+
+fn challenge(group: &Group, y: &BigUint, t: &BigUint) -> BigUint {
+    let mut transcript = Transcript::new(b"ecc-rust dlog");
+    transcript.append_biguint(b"p", &group.p);
+    transcript.append_biguint(b"q", &group.q);
+    transcript.append_biguint(b"g", &group.g);
+    transcript.append_biguint(b"y", y);
+    transcript.append_biguint(b"t", t);
+    transcript.challenge_biguint(b"c", &group.q)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod toy_example {
+        use super::*;
+
+        fn setup() -> (Group, BigUint, BigUint) {
+            let group = Group::new(
+                BigUint::from(23u32),
+                BigUint::from(11u32),
+                BigUint::from(4u32),
+                BigUint::from(9u32),
+            );
+            let x = BigUint::from(2u32);
+            let k = BigUint::from(3u32);
+            (group, x, k)
+        }
+
+        #[test]
+        fn test_positive_case() {
+            let (group, x, k) = setup();
+            let y = FiniteField::exp(&group.g, &x, &group.p);
+
+            let proof = prove(&group, &x, &k);
+            assert!(verify(&group, &y, &proof));
+        }
+
+        #[test]
+        fn test_negative_case() {
+            let (group, x, k) = setup();
+            let proof = prove(&group, &x, &k);
+
+            // the proof does not transfer to a different public value
+            let other_y = FiniteField::exp(&group.g, &BigUint::from(3u32), &group.p);
+            assert!(!verify(&group, &other_y, &proof));
+
+            // nor does a tampered response
+            let y = FiniteField::exp(&group.g, &x, &group.p);
+            let tampered = DlogProof {
+                s: FiniteField::add(&proof.s, &BigUint::from(1u32), &group.q),
+                ..proof
+            };
+            assert!(!verify(&group, &y, &tampered));
+        }
+
+        #[test]
+        fn test_edge_cases() {
+            let (group, _, k) = setup();
+
+            // Edge case: x = 0
+            let x = BigUint::from(0u32);
+            let y = FiniteField::exp(&group.g, &x, &group.p);
+            assert!(verify(&group, &y, &prove(&group, &x, &k)));
+
+            // Edge case: x = q - 1 (largest possible value)
+            let x = &group.q - BigUint::from(1u32);
+            let y = FiniteField::exp(&group.g, &x, &group.p);
+            assert!(verify(&group, &y, &prove(&group, &x, &k)));
+        }
+    }
+
+    mod real_example {
+        use super::*;
+
+        // RFC 5114, 1024-bit MODP Group with 160-bit Prime Order Subgroup
+        fn setup() -> Group {
+            let p = BigUint::parse_bytes(
+                b"B10B8F96A080E01DDE92DE5EAE5D54EC52C99FBCFB06A3C69A6A9DCA52D23B616073E28675A23D189838EF1E2EE652C013ECB4AEA906112324975C3CD49B83BFACCBDD7D90C4BD7098488E9C219A73724EFFD6FAE5644738FAA31A4FF55BCCC0A151AF5F0DC8B4BD45BF37DF365C1A65E68CFDA76D4DA708DF1FB2BC2E4A4371",
+                16,
+            )
+            .unwrap();
+            let q = BigUint::parse_bytes(b"F518AA8781A8DF278ABA4E7D64B7CB9D49462353", 16).unwrap();
+            let g = BigUint::parse_bytes(
+                b"A4D1CBD5C3FD34126765A442EFB99905F8104DD258AC507FD6406CFF14266D31266FEA1E5C41564B777E690F5504F213160217B4B01B886A5E91547F9E2749F4D7FBD7D3B9A92EE1909D0D2263F80A76A6A24C087A091F531DBF0A0169B6A28AD662A4D18E73AFA32D779D5918D08BC8858F4DCEF97C2A24855E6EEB22B3B2E5",
+                16,
+            )
+            .unwrap();
+            let h = g.modpow(&BigUint::from(2u32), &p);
+            Group::new(p, q, g, h)
+        }
+
+        #[test]
+        fn test_positive_case() {
+            let group = setup();
+            let x = BigUint::parse_bytes(b"1D8EF5BD3B7CBFCC0A53B6AEF4E6D3C2A5A1C6A8", 16).unwrap();
+            let k = BigUint::parse_bytes(b"5E2F1C0D9B8A79685746352413021F0E1D2C3B4A", 16).unwrap();
+            let y = FiniteField::exp(&group.g, &x, &group.p);
+
+            assert!(verify(&group, &y, &prove(&group, &x, &k)));
+        }
+
+        #[test]
+        fn test_negative_case() {
+            let group = setup();
+            let x = BigUint::from(123456789u32);
+            let k = BigUint::from(987654321u32);
+
+            // a proof for x does not verify against g^(x + 1)
+            let y = FiniteField::exp(&group.g, &(&x + 1u32), &group.p);
+            assert!(!verify(&group, &y, &prove(&group, &x, &k)));
+        }
+    }
+}
@@ -1 +1,4 @@
-pub mod chaum_pedersen;
\ No newline at end of file
+pub mod chaum_pedersen;
+pub mod dlog;
+pub mod transcript;
+pub mod vrf;
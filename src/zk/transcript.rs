@@ -0,0 +1,112 @@
+// Fiat-Shamir transcript
+
+use alloc::vec::Vec;
+use num_bigint::BigUint;
+use sha2::{Digest, Sha256};
+
+/// A running SHA-256 hash of a proof's public values, from which non-interactive
+/// proofs derive their challenges.
+///
+/// Every value is absorbed with its label and both are length-prefixed, so
+/// different sequences of `(label, value)` pairs never hash the same bytes.
+/// Each challenge is absorbed back into the transcript, so successive
+/// challenges differ.
+#[derive(Clone)]
+pub struct Transcript {
+    hasher: Sha256,
+}
+
+impl Transcript {
+    /// Starts a transcript bound to a protocol name, e.g. `b"ecc-rust dlog"`
+    pub fn new(domain: &[u8]) -> Self {
+        let mut transcript = Transcript {
+            hasher: Sha256::new(),
+        };
+        transcript.append_bytes(b"domain", domain);
+        transcript
+    }
+
+    pub fn append_bytes(&mut self, label: &[u8], bytes: &[u8]) {
+        self.hasher.update((label.len() as u64).to_be_bytes());
+        self.hasher.update(label);
+        self.hasher.update((bytes.len() as u64).to_be_bytes());
+        self.hasher.update(bytes);
+    }
+
+    pub fn append_biguint(&mut self, label: &[u8], value: &BigUint) {
+        self.append_bytes(label, &value.to_bytes_be());
+    }
+
+    /// Derives a challenge in `[0, modulus)`.
+    ///
+    /// Hashes the transcript with a block counter until it has 128 bits more
+    /// than `modulus`, so the reduction is statistically close to uniform.
+    pub fn challenge_biguint(&mut self, label: &[u8], modulus: &BigUint) -> BigUint {
+        self.append_bytes(b"challenge", label);
+
+        let needed = (modulus.bits() as usize + 128).div_ceil(8);
+        let mut output = Vec::with_capacity(needed + 32);
+        let mut block = 0u32;
+        while output.len() < needed {
+            let digest = self
+                .hasher
+                .clone()
+                .chain_update(block.to_be_bytes())
+                .finalize();
+            output.extend_from_slice(&digest);
+            block += 1;
+        }
+
+        let challenge = BigUint::from_bytes_be(&output[..needed]) % modulus;
+        self.append_biguint(label, &challenge);
+        challenge
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn challenge_for(values: &[(&[u8], u32)]) -> BigUint {
+        let mut transcript = Transcript::new(b"test");
+        for (label, value) in values {
+            transcript.append_biguint(label, &BigUint::from(*value));
+        }
+        transcript.challenge_biguint(b"c", &BigUint::from(1_000_003u32))
+    }
+
+    #[test]
+    fn test_same_sequence_same_challenge() {
+        let values: &[(&[u8], u32)] = &[(b"y", 42), (b"t", 7)];
+        assert_eq!(challenge_for(values), challenge_for(values));
+    }
+
+    #[test]
+    fn test_different_sequence_different_challenge() {
+        let base = challenge_for(&[(b"y", 42), (b"t", 7)]);
+        assert_ne!(base, challenge_for(&[(b"y", 42), (b"t", 8)]));
+        assert_ne!(base, challenge_for(&[(b"t", 7), (b"y", 42)]));
+        assert_ne!(base, challenge_for(&[(b"yt", 42), (b"", 7)]));
+        assert_ne!(base, challenge_for(&[(b"y", 42)]));
+
+        let mut other_domain = Transcript::new(b"other");
+        other_domain.append_biguint(b"y", &BigUint::from(42u32));
+        other_domain.append_biguint(b"t", &BigUint::from(7u32));
+        assert_ne!(
+            base,
+            other_domain.challenge_biguint(b"c", &BigUint::from(1_000_003u32))
+        );
+    }
+
+    #[test]
+    fn test_successive_challenges_differ() {
+        let modulus =
+            BigUint::parse_bytes(b"F518AA8781A8DF278ABA4E7D64B7CB9D49462353", 16).unwrap();
+        let mut transcript = Transcript::new(b"test");
+        let first = transcript.challenge_biguint(b"c", &modulus);
+        let second = transcript.challenge_biguint(b"c", &modulus);
+
+        assert!(first < modulus && second < modulus);
+        assert_ne!(first, second);
+    }
+}